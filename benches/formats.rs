@@ -0,0 +1,75 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use utcize::formats::{CompiledFormats, default_formats};
+use utcize::types::ParsedDatetime;
+
+const INPUTS: &[&str] = &[
+    "2023-06-01 10:00:00",
+    "01/06/2023 10:00",
+    "20230601T100000",
+    "Thu, 01 Jun 2023 10:00:00 +0700",
+];
+
+const CUSTOM: &[&str] = &["%Y|%m|%d %H:%M", "%d.%B.%Y %H:%M"];
+
+/// The pre-`CompiledFormats` path: collect every format into fresh `String`s and let chrono
+/// re-tokenize each one for every attempt.
+fn parse_uncompiled(s: &str, prefer_eu: bool, custom_formats: &[&str]) -> Option<ParsedDatetime> {
+    let mut formats: Vec<String> = vec![];
+    formats.extend(custom_formats.iter().map(|s| s.to_string()));
+    formats.extend(
+        default_formats(prefer_eu)
+            .into_iter()
+            .map(|s| s.to_string()),
+    );
+
+    for fmt in formats {
+        let fmt_str = fmt.as_str();
+
+        if let Ok(dt) = DateTime::parse_from_str(s, fmt_str) {
+            return Some(ParsedDatetime::WithTimezone(dt.with_timezone(&Utc)));
+        }
+
+        if !fmt_str.contains("%z") && !fmt_str.contains("%:z") {
+            if let Ok(ndt) = NaiveDateTime::parse_from_str(s, fmt_str) {
+                return Some(ParsedDatetime::Naive(ndt));
+            }
+
+            if let Ok(date) = NaiveDate::parse_from_str(s, fmt_str)
+                && let Some(ndt) = date.and_hms_opt(0, 0, 0)
+            {
+                return Some(ParsedDatetime::Naive(ndt));
+            }
+        }
+    }
+
+    None
+}
+
+fn bench_formats(c: &mut Criterion) {
+    let mut group = c.benchmark_group("flexible_formats");
+
+    group.bench_function("uncompiled", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                black_box(parse_uncompiled(black_box(input), true, CUSTOM));
+            }
+        })
+    });
+
+    let customs = CompiledFormats::new(CUSTOM);
+    let defaults = CompiledFormats::defaults(true);
+    group.bench_function("compiled", |b| {
+        b.iter(|| {
+            for input in INPUTS {
+                let input = black_box(input);
+                black_box(customs.parse(input).or_else(|| defaults.parse(input)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_formats);
+criterion_main!(benches);
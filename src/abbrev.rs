@@ -0,0 +1,233 @@
+use crate::error::TimeParseError;
+use chrono::{FixedOffset, NaiveDateTime, TimeDelta, TimeZone};
+use chrono_tz::{OffsetComponents, Tz};
+
+/// One meaning of a timezone abbreviation such as `PDT` or `WIB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Abbreviation {
+    /// The abbreviation, in upper case.
+    pub abbreviation: &'static str,
+    /// The UTC offset in seconds (east of UTC is positive).
+    pub offset_seconds: i32,
+    /// `true` if the abbreviation names a daylight saving (summer) time.
+    pub is_dst: bool,
+    /// ISO 3166 country codes where this meaning is used, for disambiguation.
+    pub countries: &'static [&'static str],
+    /// A representative IANA timezone, used to check daylight abbreviations against the date.
+    pub zone: &'static str,
+}
+
+impl Abbreviation {
+    /// Returns the UTC offset of this abbreviation.
+    pub fn offset(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.offset_seconds).expect("abbreviation offsets are in range")
+    }
+
+    /// Checks that a daylight abbreviation is used on a date when its zone observes daylight
+    /// saving time, e.g. that `PDT` is not used in January.
+    ///
+    /// Standard-time abbreviations are not checked, since they are commonly used year-round.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError::AbbreviationMismatch`] if the zone is on standard time.
+    pub fn check_date(&self, local: &NaiveDateTime) -> Result<(), TimeParseError> {
+        if !self.is_dst {
+            return Ok(());
+        }
+
+        let Ok(tz) = self.zone.parse::<Tz>() else {
+            return Ok(());
+        };
+        let utc = *local - TimeDelta::seconds(i64::from(self.offset_seconds));
+        if tz.offset_from_utc_datetime(&utc).dst_offset().is_zero() {
+            return Err(TimeParseError::AbbreviationMismatch {
+                abbreviation: self.abbreviation.to_string(),
+                datetime: *local,
+                zone: self.zone.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+macro_rules! abbr {
+    ($abbr:literal, $h:literal : $m:literal, $dst:literal, [$($country:literal),*], $zone:literal) => {
+        Abbreviation {
+            abbreviation: $abbr,
+            offset_seconds: ($h * 3600) + if $h < 0 { -$m * 60 } else { $m * 60 },
+            is_dst: $dst,
+            countries: &[$($country),*],
+            zone: $zone,
+        }
+    };
+}
+
+/// Known timezone abbreviations. An abbreviation listed more than once is ambiguous.
+static ABBREVIATIONS: &[Abbreviation] = &[
+    // Universal
+    abbr!("UTC", 0:0, false, [], "Etc/UTC"),
+    abbr!("UT", 0:0, false, [], "Etc/UTC"),
+    abbr!("GMT", 0:0, false, [], "Etc/GMT"),
+    // North America
+    abbr!("EST", -5:0, false, ["US", "CA"], "America/New_York"),
+    abbr!("EDT", -4:0, true, ["US", "CA"], "America/New_York"),
+    abbr!("CST", -6:0, false, ["US", "CA", "MX"], "America/Chicago"),
+    abbr!("CDT", -5:0, true, ["US", "CA", "MX"], "America/Chicago"),
+    abbr!("MST", -7:0, false, ["US", "CA", "MX"], "America/Denver"),
+    abbr!("MDT", -6:0, true, ["US", "CA", "MX"], "America/Denver"),
+    abbr!("PST", -8:0, false, ["US", "CA", "MX"], "America/Los_Angeles"),
+    abbr!("PDT", -7:0, true, ["US", "CA", "MX"], "America/Los_Angeles"),
+    abbr!("AKST", -9:0, false, ["US"], "America/Anchorage"),
+    abbr!("AKDT", -8:0, true, ["US"], "America/Anchorage"),
+    abbr!("HST", -10:0, false, ["US"], "Pacific/Honolulu"),
+    abbr!("AST", -4:0, false, ["CA", "PR", "VI"], "America/Halifax"),
+    abbr!("ADT", -3:0, true, ["CA"], "America/Halifax"),
+    abbr!("NST", -3:30, false, ["CA"], "America/St_Johns"),
+    abbr!("NDT", -2:30, true, ["CA"], "America/St_Johns"),
+    // Central and South America
+    abbr!("CST", -5:0, false, ["CU"], "America/Havana"),
+    abbr!("CDT", -4:0, true, ["CU"], "America/Havana"),
+    abbr!("BRT", -3:0, false, ["BR"], "America/Sao_Paulo"),
+    abbr!("ART", -3:0, false, ["AR"], "America/Argentina/Buenos_Aires"),
+    abbr!("CLT", -4:0, false, ["CL"], "America/Santiago"),
+    abbr!("CLST", -3:0, true, ["CL"], "America/Santiago"),
+    // Europe
+    abbr!("WET", 0:0, false, ["PT", "ES"], "Europe/Lisbon"),
+    abbr!("WEST", 1:0, true, ["PT", "ES"], "Europe/Lisbon"),
+    abbr!("BST", 1:0, true, ["GB"], "Europe/London"),
+    abbr!("IST", 1:0, false, ["IE"], "Europe/Dublin"),
+    abbr!("CET", 1:0, false, ["DE", "FR", "IT", "ES", "NL", "BE", "AT", "CH", "PL", "SE", "NO", "DK", "CZ", "HU"], "Europe/Paris"),
+    abbr!("CEST", 2:0, true, ["DE", "FR", "IT", "ES", "NL", "BE", "AT", "CH", "PL", "SE", "NO", "DK", "CZ", "HU"], "Europe/Paris"),
+    abbr!("EET", 2:0, false, ["GR", "FI", "RO", "BG", "UA", "EG"], "Europe/Athens"),
+    abbr!("EEST", 3:0, true, ["GR", "FI", "RO", "BG", "UA"], "Europe/Athens"),
+    abbr!("MSK", 3:0, false, ["RU"], "Europe/Moscow"),
+    // Africa and the Middle East
+    abbr!("WAT", 1:0, false, ["NG", "CM", "AO"], "Africa/Lagos"),
+    abbr!("CAT", 2:0, false, ["MZ", "ZM", "ZW"], "Africa/Maputo"),
+    abbr!("SAST", 2:0, false, ["ZA"], "Africa/Johannesburg"),
+    abbr!("EAT", 3:0, false, ["KE", "TZ", "UG", "ET"], "Africa/Nairobi"),
+    abbr!("IST", 2:0, false, ["IL"], "Asia/Jerusalem"),
+    abbr!("IDT", 3:0, true, ["IL"], "Asia/Jerusalem"),
+    abbr!("AST", 3:0, false, ["SA", "IQ", "KW", "QA"], "Asia/Riyadh"),
+    abbr!("GST", 4:0, false, ["AE", "OM"], "Asia/Dubai"),
+    // Asia
+    abbr!("PKT", 5:0, false, ["PK"], "Asia/Karachi"),
+    abbr!("IST", 5:30, false, ["IN"], "Asia/Kolkata"),
+    abbr!("NPT", 5:45, false, ["NP"], "Asia/Kathmandu"),
+    abbr!("ICT", 7:0, false, ["TH", "VN", "LA", "KH"], "Asia/Bangkok"),
+    abbr!("WIB", 7:0, false, ["ID"], "Asia/Jakarta"),
+    abbr!("WITA", 8:0, false, ["ID"], "Asia/Makassar"),
+    abbr!("WIT", 9:0, false, ["ID"], "Asia/Jayapura"),
+    abbr!("CST", 8:0, false, ["CN", "TW"], "Asia/Shanghai"),
+    abbr!("HKT", 8:0, false, ["HK"], "Asia/Hong_Kong"),
+    abbr!("SGT", 8:0, false, ["SG"], "Asia/Singapore"),
+    abbr!("MYT", 8:0, false, ["MY"], "Asia/Kuala_Lumpur"),
+    abbr!("PHT", 8:0, false, ["PH"], "Asia/Manila"),
+    abbr!("JST", 9:0, false, ["JP"], "Asia/Tokyo"),
+    abbr!("KST", 9:0, false, ["KR"], "Asia/Seoul"),
+    // Oceania
+    abbr!("AWST", 8:0, false, ["AU"], "Australia/Perth"),
+    abbr!("ACST", 9:30, false, ["AU"], "Australia/Adelaide"),
+    abbr!("ACDT", 10:30, true, ["AU"], "Australia/Adelaide"),
+    abbr!("AEST", 10:0, false, ["AU"], "Australia/Sydney"),
+    abbr!("AEDT", 11:0, true, ["AU"], "Australia/Sydney"),
+    abbr!("NZST", 12:0, false, ["NZ"], "Pacific/Auckland"),
+    abbr!("NZDT", 13:0, true, ["NZ"], "Pacific/Auckland"),
+];
+
+/// Returns every known meaning of a timezone abbreviation.
+///
+/// Matching is case-sensitive, since abbreviations are written in upper case and lower-case
+/// words (e.g. `est`, `art`) are too easily confused with ordinary text.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::abbrev::lookup_abbreviation;
+///
+/// assert_eq!(lookup_abbreviation("WIB").len(), 1);
+/// assert_eq!(lookup_abbreviation("IST").len(), 3);
+/// assert!(lookup_abbreviation("XYZ").is_empty());
+/// ```
+pub fn lookup_abbreviation(abbr: &str) -> Vec<&'static Abbreviation> {
+    ABBREVIATIONS
+        .iter()
+        .filter(|a| a.abbreviation == abbr)
+        .collect()
+}
+
+/// Resolves a timezone abbreviation to a single meaning.
+///
+/// Abbreviations with several meanings (e.g. `IST` for India, Ireland and Israel) are resolved
+/// through `preference`, a list of ISO 3166 country codes tried in order.
+///
+/// # Errors
+///
+/// - [`TimeParseError::UnknownTimezone`] if the abbreviation is not known.
+/// - [`TimeParseError::AmbiguousAbbreviation`] if it has several meanings and none of them
+///   is used in a preferred country.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::abbrev::resolve_abbreviation;
+///
+/// let ist = resolve_abbreviation::<&str>("IST", &["IN"]).unwrap();
+/// assert_eq!(ist.offset_seconds, 5 * 3600 + 30 * 60);
+///
+/// assert!(resolve_abbreviation::<&str>("IST", &[]).is_err());
+/// ```
+pub fn resolve_abbreviation<S>(
+    abbr: &str,
+    preference: &[S],
+) -> Result<&'static Abbreviation, TimeParseError>
+where
+    S: AsRef<str>,
+{
+    let candidates = lookup_abbreviation(abbr);
+    match candidates.as_slice() {
+        [] => Err(TimeParseError::UnknownTimezone {
+            name: abbr.to_string(),
+            suggestions: Vec::new(),
+        }),
+        [single] => Ok(single),
+        _ => preference
+            .iter()
+            .find_map(|country| {
+                candidates.iter().copied().find(|candidate| {
+                    candidate
+                        .countries
+                        .iter()
+                        .any(|c| c.eq_ignore_ascii_case(country.as_ref()))
+                })
+            })
+            .ok_or_else(|| TimeParseError::AmbiguousAbbreviation {
+                abbreviation: abbr.to_string(),
+                candidates: candidates.iter().map(|c| c.zone.to_string()).collect(),
+            }),
+    }
+}
+
+/// Splits a trailing known abbreviation off a datetime string, e.g. `2023-06-01 10:00 WIB`
+/// or `20230601100000UTC`.
+pub(crate) fn split_trailing_abbreviation(s: &str) -> Option<(&str, &str)> {
+    let start = s.trim_end_matches(|c: char| c.is_ascii_uppercase()).len();
+    let (rest, abbr) = s.split_at(start);
+    let rest = rest.trim_end();
+    let attached =
+        !rest.is_empty() && rest.len() == start && !rest.ends_with(|c: char| c.is_ascii_digit());
+
+    if rest.is_empty() || attached || lookup_abbreviation(abbr).is_empty() {
+        return None;
+    }
+    Some((rest, abbr))
+}
+
+/// Splits two or more upper-case letters attached to a trailing digit off a datetime string,
+/// e.g. `XYZ` in `20230601100000XYZ`, whether or not they are a known abbreviation.
+pub(crate) fn split_attached_letters(s: &str) -> Option<(&str, &str)> {
+    let start = s.trim_end_matches(|c: char| c.is_ascii_uppercase()).len();
+    let (rest, letters) = s.split_at(start);
+    (letters.len() >= 2 && rest.ends_with(|c: char| c.is_ascii_digit())).then_some((rest, letters))
+}
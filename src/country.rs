@@ -0,0 +1,718 @@
+use crate::error::TimeParseError;
+
+/// Timezones by country, from the tz database `zone1970.tab`.
+///
+/// Each entry is `(countries, IANA zone, comment)`. Countries are the comma-separated ISO 3166
+/// codes of the countries the zone covers, the most populous first. The comment describes the
+/// part of the country the zone covers, and is empty for countries with a single zone.
+static COUNTRY_ZONES: &[(&str, &str, &str)] = &[
+    ("AD", "Europe/Andorra", ""),
+    ("AE,OM,RE,SC,TF", "Asia/Dubai", "Crozet"),
+    ("AF", "Asia/Kabul", ""),
+    ("AL", "Europe/Tirane", ""),
+    ("AM", "Asia/Yerevan", ""),
+    ("AQ", "Antarctica/Casey", "Casey"),
+    ("AQ", "Antarctica/Davis", "Davis"),
+    ("AQ", "Antarctica/Mawson", "Mawson"),
+    ("AQ", "Antarctica/Palmer", "Palmer"),
+    ("AQ", "Antarctica/Rothera", "Rothera"),
+    ("AQ", "Antarctica/Troll", "Troll"),
+    ("AQ", "Antarctica/Vostok", "Vostok"),
+    (
+        "AR",
+        "America/Argentina/Buenos_Aires",
+        "Buenos Aires (BA, CF)",
+    ),
+    (
+        "AR",
+        "America/Argentina/Cordoba",
+        "most areas: CB, CC, CN, ER, FM, MN, SE, SF",
+    ),
+    ("AR", "America/Argentina/Salta", "Salta (SA, LP, NQ, RN)"),
+    ("AR", "America/Argentina/Jujuy", "Jujuy (JY)"),
+    ("AR", "America/Argentina/Tucuman", "Tucumán (TM)"),
+    (
+        "AR",
+        "America/Argentina/Catamarca",
+        "Catamarca (CT), Chubut (CH)",
+    ),
+    ("AR", "America/Argentina/La_Rioja", "La Rioja (LR)"),
+    ("AR", "America/Argentina/San_Juan", "San Juan (SJ)"),
+    ("AR", "America/Argentina/Mendoza", "Mendoza (MZ)"),
+    ("AR", "America/Argentina/San_Luis", "San Luis (SL)"),
+    ("AR", "America/Argentina/Rio_Gallegos", "Santa Cruz (SC)"),
+    ("AR", "America/Argentina/Ushuaia", "Tierra del Fuego (TF)"),
+    ("AS,UM", "Pacific/Pago_Pago", "Midway"),
+    ("AT", "Europe/Vienna", ""),
+    ("AU", "Australia/Lord_Howe", "Lord Howe Island"),
+    ("AU", "Antarctica/Macquarie", "Macquarie Island"),
+    ("AU", "Australia/Hobart", "Tasmania"),
+    ("AU", "Australia/Melbourne", "Victoria"),
+    ("AU", "Australia/Sydney", "New South Wales (most areas)"),
+    (
+        "AU",
+        "Australia/Broken_Hill",
+        "New South Wales (Yancowinna)",
+    ),
+    ("AU", "Australia/Brisbane", "Queensland (most areas)"),
+    (
+        "AU",
+        "Australia/Lindeman",
+        "Queensland (Whitsunday Islands)",
+    ),
+    ("AU", "Australia/Adelaide", "South Australia"),
+    ("AU", "Australia/Darwin", "Northern Territory"),
+    ("AU", "Australia/Perth", "Western Australia (most areas)"),
+    ("AU", "Australia/Eucla", "Western Australia (Eucla)"),
+    ("AZ", "Asia/Baku", ""),
+    ("BB", "America/Barbados", ""),
+    ("BD", "Asia/Dhaka", ""),
+    ("BE,LU,NL", "Europe/Brussels", ""),
+    ("BG", "Europe/Sofia", ""),
+    ("BM", "Atlantic/Bermuda", ""),
+    ("BO", "America/La_Paz", ""),
+    ("BR", "America/Noronha", "Atlantic islands"),
+    ("BR", "America/Belem", "Pará (east), Amapá"),
+    (
+        "BR",
+        "America/Fortaleza",
+        "Brazil (northeast: MA, PI, CE, RN, PB)",
+    ),
+    ("BR", "America/Recife", "Pernambuco"),
+    ("BR", "America/Araguaina", "Tocantins"),
+    ("BR", "America/Maceio", "Alagoas, Sergipe"),
+    ("BR", "America/Bahia", "Bahia"),
+    (
+        "BR",
+        "America/Sao_Paulo",
+        "Brazil (southeast: GO, DF, MG, ES, RJ, SP, PR, SC, RS)",
+    ),
+    ("BR", "America/Campo_Grande", "Mato Grosso do Sul"),
+    ("BR", "America/Cuiaba", "Mato Grosso"),
+    ("BR", "America/Santarem", "Pará (west)"),
+    ("BR", "America/Porto_Velho", "Rondônia"),
+    ("BR", "America/Boa_Vista", "Roraima"),
+    ("BR", "America/Manaus", "Amazonas (east)"),
+    ("BR", "America/Eirunepe", "Amazonas (west)"),
+    ("BR", "America/Rio_Branco", "Acre"),
+    ("BT", "Asia/Thimphu", ""),
+    ("BY", "Europe/Minsk", ""),
+    ("BZ", "America/Belize", ""),
+    ("CA", "America/St_Johns", "Newfoundland, Labrador (SE)"),
+    ("CA", "America/Halifax", "Atlantic - NS (most areas), PE"),
+    ("CA", "America/Glace_Bay", "Atlantic - NS (Cape Breton)"),
+    ("CA", "America/Moncton", "Atlantic - New Brunswick"),
+    (
+        "CA",
+        "America/Goose_Bay",
+        "Atlantic - Labrador (most areas)",
+    ),
+    ("CA,BS", "America/Toronto", "Eastern - ON & QC (most areas)"),
+    ("CA", "America/Iqaluit", "Eastern - NU (most areas)"),
+    ("CA", "America/Winnipeg", "Central - ON (west), Manitoba"),
+    ("CA", "America/Resolute", "Central - NU (Resolute)"),
+    ("CA", "America/Rankin_Inlet", "Central - NU (central)"),
+    ("CA", "America/Regina", "CST - SK (most areas)"),
+    ("CA", "America/Swift_Current", "CST - SK (midwest)"),
+    (
+        "CA",
+        "America/Edmonton",
+        "Mountain - AB, BC(E), NT(E), SK(W)",
+    ),
+    ("CA", "America/Cambridge_Bay", "Mountain - NU (west)"),
+    ("CA", "America/Inuvik", "Mountain - NT (west)"),
+    (
+        "CA",
+        "America/Dawson_Creek",
+        "MST - BC (Dawson Cr, Ft St John)",
+    ),
+    ("CA", "America/Fort_Nelson", "MST - BC (Ft Nelson)"),
+    ("CA", "America/Whitehorse", "MST - Yukon (east)"),
+    ("CA", "America/Dawson", "MST - Yukon (west)"),
+    ("CA", "America/Vancouver", "Pacific - BC (most areas)"),
+    ("CH,DE,LI", "Europe/Zurich", "Büsingen"),
+    ("CI,BF,GH,GM,GN,IS,ML,MR,SH,SL,SN,TG", "Africa/Abidjan", ""),
+    ("CK", "Pacific/Rarotonga", ""),
+    ("CL", "America/Santiago", "most of Chile"),
+    ("CL", "America/Coyhaique", "Aysén Region"),
+    ("CL", "America/Punta_Arenas", "Magallanes Region"),
+    ("CL", "Pacific/Easter", "Easter Island"),
+    ("CN", "Asia/Shanghai", "Beijing Time"),
+    ("CN", "Asia/Urumqi", "Xinjiang Time"),
+    ("CO", "America/Bogota", ""),
+    ("CR", "America/Costa_Rica", ""),
+    ("CU", "America/Havana", ""),
+    ("CV", "Atlantic/Cape_Verde", ""),
+    ("CY", "Asia/Nicosia", "most of Cyprus"),
+    ("CY", "Asia/Famagusta", "Northern Cyprus"),
+    ("CZ,SK", "Europe/Prague", ""),
+    ("DE,DK,NO,SE,SJ", "Europe/Berlin", "most of Germany"),
+    ("DO", "America/Santo_Domingo", ""),
+    ("DZ", "Africa/Algiers", ""),
+    ("EC", "America/Guayaquil", "Ecuador (mainland)"),
+    ("EC", "Pacific/Galapagos", "Galápagos Islands"),
+    ("EE", "Europe/Tallinn", ""),
+    ("EG", "Africa/Cairo", ""),
+    ("EH", "Africa/El_Aaiun", ""),
+    ("ES", "Europe/Madrid", "Spain (mainland)"),
+    ("ES", "Africa/Ceuta", "Ceuta, Melilla"),
+    ("ES", "Atlantic/Canary", "Canary Islands"),
+    ("FI,AX", "Europe/Helsinki", ""),
+    ("FJ", "Pacific/Fiji", ""),
+    ("FK", "Atlantic/Stanley", ""),
+    ("FM", "Pacific/Kosrae", "Kosrae"),
+    ("FO", "Atlantic/Faroe", ""),
+    ("FR,MC", "Europe/Paris", ""),
+    ("GB,GG,IM,JE", "Europe/London", ""),
+    ("GE", "Asia/Tbilisi", ""),
+    ("GF", "America/Cayenne", ""),
+    ("GI", "Europe/Gibraltar", ""),
+    ("GL", "America/Nuuk", "most of Greenland"),
+    ("GL", "America/Danmarkshavn", "National Park (east coast)"),
+    (
+        "GL",
+        "America/Scoresbysund",
+        "Scoresbysund/Ittoqqortoormiit",
+    ),
+    ("GL", "America/Thule", "Thule/Pituffik"),
+    ("GR", "Europe/Athens", ""),
+    ("GS", "Atlantic/South_Georgia", ""),
+    ("GT", "America/Guatemala", ""),
+    ("GU,MP", "Pacific/Guam", ""),
+    ("GW", "Africa/Bissau", ""),
+    ("GY", "America/Guyana", ""),
+    ("HK", "Asia/Hong_Kong", ""),
+    ("HN", "America/Tegucigalpa", ""),
+    ("HT", "America/Port-au-Prince", ""),
+    ("HU", "Europe/Budapest", ""),
+    ("ID", "Asia/Jakarta", "Java, Sumatra"),
+    ("ID", "Asia/Pontianak", "Borneo (west, central)"),
+    (
+        "ID",
+        "Asia/Makassar",
+        "Borneo (east, south), Sulawesi/Celebes, Bali, Nusa Tengarra, Timor (west)",
+    ),
+    (
+        "ID",
+        "Asia/Jayapura",
+        "New Guinea (West Papua / Irian Jaya), Malukus/Moluccas",
+    ),
+    ("IE", "Europe/Dublin", ""),
+    ("IL", "Asia/Jerusalem", ""),
+    ("IN", "Asia/Kolkata", ""),
+    ("IO", "Indian/Chagos", ""),
+    ("IQ", "Asia/Baghdad", ""),
+    ("IR", "Asia/Tehran", ""),
+    ("IT,SM,VA", "Europe/Rome", ""),
+    ("JM", "America/Jamaica", ""),
+    ("JO", "Asia/Amman", ""),
+    ("JP,AU", "Asia/Tokyo", "Eyre Bird Observatory"),
+    ("KE,DJ,ER,ET,KM,MG,SO,TZ,UG,YT", "Africa/Nairobi", ""),
+    ("KG", "Asia/Bishkek", ""),
+    (
+        "KI,MH,TV,UM,WF",
+        "Pacific/Tarawa",
+        "Gilberts, Marshalls, Wake",
+    ),
+    ("KI", "Pacific/Kanton", "Phoenix Islands"),
+    ("KI", "Pacific/Kiritimati", "Line Islands"),
+    ("KP", "Asia/Pyongyang", ""),
+    ("KR", "Asia/Seoul", ""),
+    ("KZ", "Asia/Almaty", "most of Kazakhstan"),
+    ("KZ", "Asia/Qyzylorda", "Qyzylorda/Kyzylorda/Kzyl-Orda"),
+    ("KZ", "Asia/Qostanay", "Qostanay/Kostanay/Kustanay"),
+    ("KZ", "Asia/Aqtobe", "Aqtöbe/Aktobe"),
+    ("KZ", "Asia/Aqtau", "Mangghystaū/Mankistau"),
+    ("KZ", "Asia/Atyrau", "Atyraū/Atirau/Gur'yev"),
+    ("KZ", "Asia/Oral", "West Kazakhstan"),
+    ("LB", "Asia/Beirut", ""),
+    ("LK", "Asia/Colombo", ""),
+    ("LR", "Africa/Monrovia", ""),
+    ("LT", "Europe/Vilnius", ""),
+    ("LV", "Europe/Riga", ""),
+    ("LY", "Africa/Tripoli", ""),
+    ("MA", "Africa/Casablanca", ""),
+    ("MD", "Europe/Chisinau", ""),
+    ("MH", "Pacific/Kwajalein", "Kwajalein"),
+    ("MM,CC", "Asia/Yangon", ""),
+    ("MN", "Asia/Ulaanbaatar", "most of Mongolia"),
+    ("MN", "Asia/Hovd", "Bayan-Ölgii, Hovd, Uvs"),
+    ("MO", "Asia/Macau", ""),
+    ("MQ", "America/Martinique", ""),
+    ("MT", "Europe/Malta", ""),
+    ("MU", "Indian/Mauritius", ""),
+    (
+        "MV,TF",
+        "Indian/Maldives",
+        "Kerguelen, St Paul I, Amsterdam I",
+    ),
+    ("MX", "America/Mexico_City", "Central Mexico"),
+    ("MX", "America/Cancun", "Quintana Roo"),
+    ("MX", "America/Merida", "Campeche, Yucatán"),
+    (
+        "MX",
+        "America/Monterrey",
+        "Durango; Coahuila, Nuevo León, Tamaulipas (most areas)",
+    ),
+    (
+        "MX",
+        "America/Matamoros",
+        "Coahuila, Nuevo León, Tamaulipas (US border)",
+    ),
+    ("MX", "America/Chihuahua", "Chihuahua (most areas)"),
+    (
+        "MX",
+        "America/Ciudad_Juarez",
+        "Chihuahua (US border - west)",
+    ),
+    ("MX", "America/Ojinaga", "Chihuahua (US border - east)"),
+    (
+        "MX",
+        "America/Mazatlan",
+        "Baja California Sur, Nayarit (most areas), Sinaloa",
+    ),
+    ("MX", "America/Bahia_Banderas", "Bahía de Banderas"),
+    ("MX", "America/Hermosillo", "Sonora"),
+    ("MX", "America/Tijuana", "Baja California"),
+    ("MY,BN", "Asia/Kuching", "Sabah, Sarawak"),
+    (
+        "MZ,BI,BW,CD,MW,RW,ZM,ZW",
+        "Africa/Maputo",
+        "Central Africa Time",
+    ),
+    ("NA", "Africa/Windhoek", ""),
+    ("NC", "Pacific/Noumea", ""),
+    ("NF", "Pacific/Norfolk", ""),
+    (
+        "NG,AO,BJ,CD,CF,CG,CM,GA,GQ,NE",
+        "Africa/Lagos",
+        "West Africa Time",
+    ),
+    ("NI", "America/Managua", ""),
+    ("NP", "Asia/Kathmandu", ""),
+    ("NR", "Pacific/Nauru", ""),
+    ("NU", "Pacific/Niue", ""),
+    ("NZ,AQ", "Pacific/Auckland", "New Zealand time"),
+    ("NZ", "Pacific/Chatham", "Chatham Islands"),
+    (
+        "PA,CA,KY",
+        "America/Panama",
+        "EST - ON (Atikokan), NU (Coral H)",
+    ),
+    ("PE", "America/Lima", ""),
+    ("PF", "Pacific/Tahiti", "Society Islands"),
+    ("PF", "Pacific/Marquesas", "Marquesas Islands"),
+    ("PF", "Pacific/Gambier", "Gambier Islands"),
+    (
+        "PG,AQ,FM",
+        "Pacific/Port_Moresby",
+        "Papua New Guinea (most areas), Chuuk, Yap, Dumont d'Urville",
+    ),
+    ("PG", "Pacific/Bougainville", "Bougainville"),
+    ("PH", "Asia/Manila", ""),
+    ("PK", "Asia/Karachi", ""),
+    ("PL", "Europe/Warsaw", ""),
+    ("PM", "America/Miquelon", ""),
+    ("PN", "Pacific/Pitcairn", ""),
+    (
+        "PR,AG,CA,AI,AW,BL,BQ,CW,DM,GD,GP,KN,LC,MF,MS,SX,TT,VC,VG,VI",
+        "America/Puerto_Rico",
+        "AST - QC (Lower North Shore)",
+    ),
+    ("PS", "Asia/Gaza", "Gaza Strip"),
+    ("PS", "Asia/Hebron", "West Bank"),
+    ("PT", "Europe/Lisbon", "Portugal (mainland)"),
+    ("PT", "Atlantic/Madeira", "Madeira Islands"),
+    ("PT", "Atlantic/Azores", "Azores"),
+    ("PW", "Pacific/Palau", ""),
+    ("PY", "America/Asuncion", ""),
+    ("QA,BH", "Asia/Qatar", ""),
+    ("RO", "Europe/Bucharest", ""),
+    ("RS,BA,HR,ME,MK,SI", "Europe/Belgrade", ""),
+    ("RU", "Europe/Kaliningrad", "MSK-01 - Kaliningrad"),
+    ("RU", "Europe/Moscow", "MSK+00 - Moscow area"),
+    ("RU,UA", "Europe/Simferopol", "Crimea"),
+    ("RU", "Europe/Kirov", "MSK+00 - Kirov"),
+    ("RU", "Europe/Volgograd", "MSK+00 - Volgograd"),
+    ("RU", "Europe/Astrakhan", "MSK+01 - Astrakhan"),
+    ("RU", "Europe/Saratov", "MSK+01 - Saratov"),
+    ("RU", "Europe/Ulyanovsk", "MSK+01 - Ulyanovsk"),
+    ("RU", "Europe/Samara", "MSK+01 - Samara, Udmurtia"),
+    ("RU", "Asia/Yekaterinburg", "MSK+02 - Urals"),
+    ("RU", "Asia/Omsk", "MSK+03 - Omsk"),
+    ("RU", "Asia/Novosibirsk", "MSK+04 - Novosibirsk"),
+    ("RU", "Asia/Barnaul", "MSK+04 - Altai"),
+    ("RU", "Asia/Tomsk", "MSK+04 - Tomsk"),
+    ("RU", "Asia/Novokuznetsk", "MSK+04 - Kemerovo"),
+    ("RU", "Asia/Krasnoyarsk", "MSK+04 - Krasnoyarsk area"),
+    ("RU", "Asia/Irkutsk", "MSK+05 - Irkutsk, Buryatia"),
+    ("RU", "Asia/Chita", "MSK+06 - Zabaykalsky"),
+    ("RU", "Asia/Yakutsk", "MSK+06 - Lena River"),
+    ("RU", "Asia/Khandyga", "MSK+06 - Tomponsky, Ust-Maysky"),
+    ("RU", "Asia/Vladivostok", "MSK+07 - Amur River"),
+    ("RU", "Asia/Ust-Nera", "MSK+07 - Oymyakonsky"),
+    ("RU", "Asia/Magadan", "MSK+08 - Magadan"),
+    ("RU", "Asia/Sakhalin", "MSK+08 - Sakhalin Island"),
+    ("RU", "Asia/Srednekolymsk", "MSK+08 - Sakha (E), N Kuril Is"),
+    ("RU", "Asia/Kamchatka", "MSK+09 - Kamchatka"),
+    ("RU", "Asia/Anadyr", "MSK+09 - Bering Sea"),
+    ("SA,AQ,KW,YE", "Asia/Riyadh", "Syowa"),
+    ("SB,FM", "Pacific/Guadalcanal", "Pohnpei"),
+    ("SD", "Africa/Khartoum", ""),
+    (
+        "SG,AQ,MY",
+        "Asia/Singapore",
+        "peninsular Malaysia, Concordia",
+    ),
+    ("SR", "America/Paramaribo", ""),
+    ("SS", "Africa/Juba", ""),
+    ("ST", "Africa/Sao_Tome", ""),
+    ("SV", "America/El_Salvador", ""),
+    ("SY", "Asia/Damascus", ""),
+    ("TC", "America/Grand_Turk", ""),
+    ("TD", "Africa/Ndjamena", ""),
+    ("TH,CX,KH,LA,VN", "Asia/Bangkok", "north Vietnam"),
+    ("TJ", "Asia/Dushanbe", ""),
+    ("TK", "Pacific/Fakaofo", ""),
+    ("TL", "Asia/Dili", ""),
+    ("TM", "Asia/Ashgabat", ""),
+    ("TN", "Africa/Tunis", ""),
+    ("TO", "Pacific/Tongatapu", ""),
+    ("TR", "Europe/Istanbul", ""),
+    ("TW", "Asia/Taipei", ""),
+    ("UA", "Europe/Kyiv", "most of Ukraine"),
+    ("US", "America/New_York", "Eastern (most areas)"),
+    ("US", "America/Detroit", "Eastern - MI (most areas)"),
+    (
+        "US",
+        "America/Kentucky/Louisville",
+        "Eastern - KY (Louisville area)",
+    ),
+    ("US", "America/Kentucky/Monticello", "Eastern - KY (Wayne)"),
+    (
+        "US",
+        "America/Indiana/Indianapolis",
+        "Eastern - IN (most areas)",
+    ),
+    (
+        "US",
+        "America/Indiana/Vincennes",
+        "Eastern - IN (Da, Du, K, Mn)",
+    ),
+    ("US", "America/Indiana/Winamac", "Eastern - IN (Pulaski)"),
+    ("US", "America/Indiana/Marengo", "Eastern - IN (Crawford)"),
+    ("US", "America/Indiana/Petersburg", "Eastern - IN (Pike)"),
+    ("US", "America/Indiana/Vevay", "Eastern - IN (Switzerland)"),
+    ("US", "America/Chicago", "Central (most areas)"),
+    ("US", "America/Indiana/Tell_City", "Central - IN (Perry)"),
+    ("US", "America/Indiana/Knox", "Central - IN (Starke)"),
+    ("US", "America/Menominee", "Central - MI (Wisconsin border)"),
+    ("US", "America/North_Dakota/Center", "Central - ND (Oliver)"),
+    (
+        "US",
+        "America/North_Dakota/New_Salem",
+        "Central - ND (Morton rural)",
+    ),
+    ("US", "America/North_Dakota/Beulah", "Central - ND (Mercer)"),
+    ("US", "America/Denver", "Mountain (most areas)"),
+    ("US", "America/Boise", "Mountain - ID (south), OR (east)"),
+    (
+        "US,CA",
+        "America/Phoenix",
+        "MST - AZ (most areas), Creston BC",
+    ),
+    ("US", "America/Los_Angeles", "Pacific"),
+    ("US", "America/Anchorage", "Alaska (most areas)"),
+    ("US", "America/Juneau", "Alaska - Juneau area"),
+    ("US", "America/Sitka", "Alaska - Sitka area"),
+    ("US", "America/Metlakatla", "Alaska - Annette Island"),
+    ("US", "America/Yakutat", "Alaska - Yakutat"),
+    ("US", "America/Nome", "Alaska (west)"),
+    ("US", "America/Adak", "Alaska - western Aleutians"),
+    ("US", "Pacific/Honolulu", "Hawaii"),
+    ("UY", "America/Montevideo", ""),
+    ("UZ", "Asia/Samarkand", "Uzbekistan (west)"),
+    ("UZ", "Asia/Tashkent", "Uzbekistan (east)"),
+    ("VE", "America/Caracas", ""),
+    ("VN", "Asia/Ho_Chi_Minh", "south Vietnam"),
+    ("VU", "Pacific/Efate", ""),
+    ("WS", "Pacific/Apia", ""),
+    ("ZA,LS,SZ", "Africa/Johannesburg", ""),
+];
+
+/// Approximate 2023 populations in millions of the countries with a million people or more,
+/// used to rank timezones.
+static COUNTRY_POPULATION: &[(&str, u32)] = &[
+    ("IN", 1430),
+    ("CN", 1410),
+    ("US", 335),
+    ("ID", 277),
+    ("PK", 240),
+    ("NG", 224),
+    ("BR", 216),
+    ("BD", 173),
+    ("RU", 144),
+    ("MX", 128),
+    ("ET", 127),
+    ("JP", 124),
+    ("PH", 117),
+    ("EG", 113),
+    ("CD", 102),
+    ("VN", 99),
+    ("IR", 89),
+    ("TR", 85),
+    ("DE", 84),
+    ("TH", 72),
+    ("GB", 68),
+    ("FR", 68),
+    ("TZ", 67),
+    ("ZA", 60),
+    ("IT", 59),
+    ("KE", 55),
+    ("MM", 54),
+    ("CO", 52),
+    ("KR", 52),
+    ("UG", 48),
+    ("SD", 48),
+    ("ES", 48),
+    ("DZ", 46),
+    ("AR", 46),
+    ("IQ", 45),
+    ("AF", 42),
+    ("CA", 39),
+    ("PL", 37),
+    ("MA", 37),
+    ("SA", 37),
+    ("UA", 37),
+    ("AO", 37),
+    ("UZ", 35),
+    ("YE", 34),
+    ("PE", 34),
+    ("MY", 34),
+    ("GH", 34),
+    ("MZ", 34),
+    ("NP", 31),
+    ("MG", 30),
+    ("CI", 29),
+    ("VE", 28),
+    ("CM", 28),
+    ("NE", 27),
+    ("AU", 27),
+    ("KP", 26),
+    ("TW", 24),
+    ("ML", 23),
+    ("BF", 23),
+    ("SY", 23),
+    ("LK", 22),
+    ("MW", 21),
+    ("ZM", 21),
+    ("CL", 20),
+    ("KZ", 20),
+    ("RO", 19),
+    ("TD", 18),
+    ("EC", 18),
+    ("GT", 18),
+    ("SN", 18),
+    ("NL", 18),
+    ("SO", 18),
+    ("KH", 17),
+    ("ZW", 17),
+    ("GN", 14),
+    ("RW", 14),
+    ("BJ", 13),
+    ("BI", 13),
+    ("TN", 12),
+    ("BO", 12),
+    ("BE", 12),
+    ("HT", 12),
+    ("JO", 11),
+    ("DO", 11),
+    ("CU", 11),
+    ("SS", 11),
+    ("SE", 11),
+    ("CZ", 11),
+    ("HN", 10),
+    ("GR", 10),
+    ("AZ", 10),
+    ("PT", 10),
+    ("PG", 10),
+    ("HU", 10),
+    ("TJ", 10),
+    ("AE", 10),
+    ("IL", 10),
+    ("BY", 9),
+    ("TG", 9),
+    ("AT", 9),
+    ("CH", 9),
+    ("SL", 9),
+    ("LA", 8),
+    ("HK", 7),
+    ("RS", 7),
+    ("NI", 7),
+    ("LY", 7),
+    ("PY", 7),
+    ("KG", 7),
+    ("BG", 6),
+    ("TM", 6),
+    ("SV", 6),
+    ("CG", 6),
+    ("SG", 6),
+    ("DK", 6),
+    ("CF", 6),
+    ("FI", 6),
+    ("SK", 5),
+    ("NO", 5),
+    ("LB", 5),
+    ("PS", 5),
+    ("NZ", 5),
+    ("CR", 5),
+    ("IE", 5),
+    ("OM", 5),
+    ("LR", 5),
+    ("MR", 5),
+    ("KW", 4),
+    ("PA", 4),
+    ("HR", 4),
+    ("GE", 4),
+    ("ER", 4),
+    ("UY", 3),
+    ("MN", 3),
+    ("BA", 3),
+    ("PR", 3),
+    ("AM", 3),
+    ("AL", 3),
+    ("LT", 3),
+    ("QA", 3),
+    ("JM", 3),
+    ("MD", 3),
+    ("NA", 3),
+    ("GM", 3),
+    ("BW", 3),
+    ("GA", 2),
+    ("LS", 2),
+    ("SI", 2),
+    ("MK", 2),
+    ("LV", 2),
+    ("GW", 2),
+    ("GQ", 2),
+    ("BH", 1),
+    ("TT", 1),
+    ("EE", 1),
+    ("TL", 1),
+    ("MU", 1),
+    ("CY", 1),
+    ("SZ", 1),
+    ("DJ", 1),
+    ("FJ", 1),
+];
+
+/// A timezone used in a country.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountryZone {
+    /// The IANA timezone, e.g. `Asia/Jakarta`.
+    pub zone: &'static str,
+
+    /// The part of the country the zone covers, e.g. `Java, Sumatra`. Empty if the zone
+    /// covers the whole country.
+    pub comment: &'static str,
+}
+
+/// Returns the timezones used in a country, given its ISO 3166 code such as `ID` or `DE`.
+///
+/// Zones the country shares with a more populous country, such as `Europe/Zurich` for the
+/// German exclave of Büsingen, come last. Codes are matched case-insensitively; unknown codes
+/// return an empty list.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::country::country_zones;
+///
+/// let zones: Vec<_> = country_zones("ID").iter().map(|z| z.zone).collect();
+/// assert_eq!(zones, ["Asia/Jakarta", "Asia/Pontianak", "Asia/Makassar", "Asia/Jayapura"]);
+/// assert_eq!(country_zones("JP")[0].zone, "Asia/Tokyo");
+/// assert!(country_zones("XX").is_empty());
+/// ```
+pub fn country_zones(code: &str) -> Vec<CountryZone> {
+    let covers = |countries: &str| {
+        countries
+            .split(',')
+            .position(|c| c.eq_ignore_ascii_case(code))
+    };
+
+    let mut zones: Vec<(bool, CountryZone)> = COUNTRY_ZONES
+        .iter()
+        .filter_map(|(countries, zone, comment)| {
+            let position = covers(countries)?;
+            Some((position > 0, CountryZone { zone, comment }))
+        })
+        .collect();
+    // Stable, so zones keep the tz database order otherwise.
+    zones.sort_by_key(|(shared, _)| *shared);
+    zones.into_iter().map(|(_, zone)| zone).collect()
+}
+
+/// Resolves an ISO 3166 country code to its IANA timezone.
+///
+/// Countries with a single timezone resolve directly. For countries with several, the first
+/// zone in `preference` used in the country is returned.
+///
+/// # Errors
+///
+/// - [`TimeParseError::UnknownTimezone`] if the country code is unknown,
+/// - [`TimeParseError::AmbiguousCountry`] if the country has several timezones and none of
+///   them is in `preference`; the error lists them all.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::country::resolve_country;
+/// use utcize::error::TimeParseError;
+///
+/// assert_eq!(resolve_country::<&str>("JP", &[]).unwrap(), "Asia/Tokyo");
+/// assert_eq!(resolve_country("ID", &["Asia/Makassar"]).unwrap(), "Asia/Makassar");
+/// assert!(matches!(
+///     resolve_country::<&str>("ID", &[]),
+///     Err(TimeParseError::AmbiguousCountry { .. })
+/// ));
+/// ```
+pub fn resolve_country<S>(code: &str, preference: &[S]) -> Result<&'static str, TimeParseError>
+where
+    S: AsRef<str>,
+{
+    let zones = country_zones(code);
+    match zones[..] {
+        [] => Err(TimeParseError::UnknownTimezone {
+            name: code.to_string(),
+            suggestions: Vec::new(),
+        }),
+        [only] => Ok(only.zone),
+        _ => preference
+            .iter()
+            .find_map(|preferred| {
+                zones
+                    .iter()
+                    .find(|z| z.zone == preferred.as_ref())
+                    .map(|z| z.zone)
+            })
+            .ok_or_else(|| TimeParseError::AmbiguousCountry {
+                country: code.to_string(),
+                candidates: zones.iter().map(|z| z.zone.to_string()).collect(),
+            }),
+    }
+}
+
+/// Returns the approximate population of a country in millions, or `0` below a million.
+pub(crate) fn country_population(code: &str) -> u32 {
+    COUNTRY_POPULATION
+        .iter()
+        .find(|(country, _)| country.eq_ignore_ascii_case(code))
+        .map_or(0, |(_, population)| *population)
+}
+
+/// Returns every zone of `zone1970.tab` with the countries it covers, in the tz database order.
+pub(crate) fn zone1970() -> impl Iterator<Item = (&'static str, &'static str)> {
+    COUNTRY_ZONES
+        .iter()
+        .map(|(countries, zone, _)| (*countries, *zone))
+}
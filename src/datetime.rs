@@ -1,37 +1,350 @@
 use crate::error::TimeParseError;
-use crate::formats::default_formats;
-use crate::types::{EpochKind, ParsedDatetime, TimeZoneParsed};
+use crate::formats::ClosestMatch;
+use crate::types::{
+    DateOrder, EpochFamily, EpochKind, EpochWindow, ParseOutcome, ParsedDatetime, SerialDateSystem,
+};
 use crate::tz::parse_timezone_str;
-use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use crate::utcizer::Utcizer;
+use chrono::format::ParseErrorKind;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+use std::borrow::Cow;
+use std::sync::OnceLock;
 
-/// Attempts to detect the kind of epoch (timestamp) based on the length of the string.
+/// The smallest unsigned value detected as an epoch: the first 9-digit number.
+const MIN_UNSIGNED_EPOCH: i64 = 100_000_000;
+
+/// Attempts to detect the kind of epoch (timestamp) of a numeric string.
 ///
-/// This function assumes:
-/// - 10 digits → seconds
-/// - 13 digits → milliseconds
-/// - 16 digits → microseconds
-/// - 19 digits → nanoseconds
+/// The string may carry a leading `+` or `-`. Detection is based on the magnitude of the value
+/// and the default [`EpochWindow`]; see [`detect_epoch_kind_in`] for details.
 ///
 /// # Arguments
 /// * `s` - A string containing a numeric epoch.
 ///
 /// # Returns
-/// * `Some(EpochKind)` if the string matches a known epoch length.
-/// * `None` if it doesn't match any known format.
+/// * `Some(EpochKind)` if the value is a plausible epoch.
+/// * `None` if the string is not numeric or the value falls outside the window.
 pub fn detect_epoch_kind(s: &str) -> Option<EpochKind> {
-    match s.len() {
-        10 => Some(EpochKind::Seconds),
-        13 => Some(EpochKind::Milliseconds),
-        16 => Some(EpochKind::Microseconds),
-        19 => Some(EpochKind::Nanoseconds),
-        _ => None,
+    let value = parse_epoch_value(s)?;
+    detect_epoch_kind_in(value, &EpochWindow::default())
+}
+
+/// Detects the kind of epoch (timestamp) of a numeric value.
+///
+/// The unit is chosen by magnitude:
+/// - `|value| < 10^11` → seconds
+/// - `|value| < 10^14` → milliseconds
+/// - `|value| < 10^17` → microseconds
+/// - otherwise → nanoseconds
+///
+/// The resulting instant must then fall inside `window`, which rejects values that only look
+/// like epochs, such as compact dates (`20230601100000`).
+///
+/// Unsigned values must also have at least 9 digits (from 1973-03-03T09:46:40Z on), so that
+/// years (`2023`), compact dates (`20230601`) and other short numbers are not read as epochs.
+/// Instants before 1970 therefore need an explicit `-` sign. Use a unit suffix or
+/// [`EpochOption::Fixed`](crate::types::EpochOption::Fixed) to read shorter values as epochs.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::datetime::detect_epoch_kind_in;
+/// use utcize::types::{EpochKind, EpochWindow};
+///
+/// let window = EpochWindow::default();
+/// assert_eq!(detect_epoch_kind_in(999_999_999, &window), Some(EpochKind::Seconds));
+/// assert_eq!(detect_epoch_kind_in(-86_400, &window), Some(EpochKind::Seconds));
+/// assert_eq!(detect_epoch_kind_in(978_307_200_000, &window), Some(EpochKind::Milliseconds));
+/// assert_eq!(detect_epoch_kind_in(20_230_601_100_000, &window), None);
+/// assert_eq!(detect_epoch_kind_in(20_230_601, &window), None);
+/// ```
+pub fn detect_epoch_kind_in(value: i64, window: &EpochWindow) -> Option<EpochKind> {
+    if (0..MIN_UNSIGNED_EPOCH).contains(&value) {
+        return None;
+    }
+
+    let magnitude = value.unsigned_abs();
+    let kind = if magnitude < 100_000_000_000 {
+        EpochKind::Seconds
+    } else if magnitude < 100_000_000_000_000 {
+        EpochKind::Milliseconds
+    } else if magnitude < 100_000_000_000_000_000 {
+        EpochKind::Microseconds
+    } else {
+        EpochKind::Nanoseconds
+    };
+
+    epoch_to_utc(value, kind)
+        .filter(|dt| window.contains(dt))
+        .map(|_| kind)
+}
+
+/// Converts a numeric epoch in the given unit to a `DateTime<Utc>`.
+///
+/// Negative values count back from 1970-01-01T00:00:00Z, so `-1` milliseconds is
+/// `1969-12-31T23:59:59.999Z`.
+///
+/// # Returns
+/// * `Some(DateTime<Utc>)` if the value is in chrono's supported range.
+/// * `None` otherwise.
+pub fn epoch_to_utc(value: i64, kind: EpochKind) -> Option<DateTime<Utc>> {
+    epoch_to_utc_from(DateTime::UNIX_EPOCH, value, kind)
+}
+
+/// Converts a numeric epoch counted in the given unit from a custom `origin`.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use utcize::datetime::epoch_to_utc_from;
+/// use utcize::types::EpochKind;
+///
+/// let origin = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+/// let dt = epoch_to_utc_from(origin, 86_400_000, EpochKind::Milliseconds).unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2000-01-02T00:00:00+00:00");
+/// ```
+pub fn epoch_to_utc_from(
+    origin: DateTime<Utc>,
+    value: i64,
+    kind: EpochKind,
+) -> Option<DateTime<Utc>> {
+    let per_second = kind.units_per_second();
+    let secs = TimeDelta::try_seconds(value.div_euclid(per_second))?;
+    let nsecs = TimeDelta::nanoseconds(value.rem_euclid(per_second) * (1_000_000_000 / per_second));
+    origin.checked_add_signed(secs)?.checked_add_signed(nsecs)
+}
+
+/// Splits an explicit unit suffix off a numeric epoch.
+///
+/// Recognized suffixes are `s`, `ms`, `us` (or `µs`) and `ns`, e.g. `1685588400123ms`.
+/// The number may carry a leading `+` or `-`.
+///
+/// # Returns
+/// * `Some((value, EpochKind))` if the string is a number followed by a known suffix.
+/// * `None` otherwise.
+pub fn split_epoch_suffix(s: &str) -> Option<(i64, EpochKind)> {
+    const SUFFIXES: [(&str, EpochKind); 5] = [
+        ("ms", EpochKind::Milliseconds),
+        ("us", EpochKind::Microseconds),
+        ("µs", EpochKind::Microseconds),
+        ("ns", EpochKind::Nanoseconds),
+        ("s", EpochKind::Seconds),
+    ];
+
+    SUFFIXES.iter().find_map(|(suffix, kind)| {
+        let value = parse_epoch_value(s.strip_suffix(suffix)?)?;
+        Some((value, *kind))
+    })
+}
+
+/// GPS-UTC offsets in seconds, with the UTC date from which each applies.
+const GPS_LEAP_SECONDS: [(i32, u32, u32, i64); 18] = [
+    (1981, 7, 1, 1),
+    (1982, 7, 1, 2),
+    (1983, 7, 1, 3),
+    (1985, 7, 1, 4),
+    (1988, 1, 1, 5),
+    (1990, 1, 1, 6),
+    (1991, 1, 1, 7),
+    (1992, 7, 1, 8),
+    (1993, 7, 1, 9),
+    (1994, 7, 1, 10),
+    (1996, 1, 1, 11),
+    (1997, 7, 1, 12),
+    (1999, 1, 1, 13),
+    (2006, 1, 1, 14),
+    (2009, 1, 1, 15),
+    (2012, 7, 1, 16),
+    (2015, 7, 1, 17),
+    (2017, 1, 1, 18),
+];
+
+/// Converts a numeric timestamp of a non-Unix [`EpochFamily`] to a `DateTime<Utc>`.
+///
+/// The value may be signed and may carry a decimal fraction; the result is exact to the
+/// nanosecond (fractions beyond that are truncated towards the past).
+///
+/// # Returns
+/// * `None` if `s` is not a decimal number.
+/// * `Some(Ok(DateTime<Utc>))` for a value in chrono's supported range.
+/// * `Some(Err(TimeParseError::InvalidFieldValue))` if the value is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::datetime::epoch_family_to_utc;
+/// use utcize::types::EpochFamily;
+///
+/// let dt = epoch_family_to_utc("133300620000000000", EpochFamily::WindowsFileTime).unwrap().unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+///
+/// let dt = epoch_family_to_utc("2460096.625", EpochFamily::JulianDay).unwrap().unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+/// ```
+pub fn epoch_family_to_utc(
+    s: &str,
+    family: EpochFamily,
+) -> Option<Result<DateTime<Utc>, TimeParseError>> {
+    let (mantissa, scale) = parse_decimal(s)?;
+
+    let out_of_range = || TimeParseError::InvalidFieldValue {
+        input: s.to_string(),
+        format: format!("{:?} timestamp", family),
+        kind: ParseErrorKind::OutOfRange,
+    };
+
+    let (origin, nanos_per_unit): (NaiveDateTime, i128) = match family {
+        EpochFamily::DotNetTicks => (ymd_hms(1, 1, 1, 0)?, 100),
+        EpochFamily::WindowsFileTime => (ymd_hms(1601, 1, 1, 0)?, 100),
+        EpochFamily::WebKit => (ymd_hms(1601, 1, 1, 0)?, 1_000),
+        EpochFamily::Cocoa => (ymd_hms(2001, 1, 1, 0)?, 1_000_000_000),
+        EpochFamily::HfsPlus => (ymd_hms(1904, 1, 1, 0)?, 1_000_000_000),
+        EpochFamily::Gps => (ymd_hms(1980, 1, 6, 0)?, 1_000_000_000),
+        EpochFamily::Ntp => (ymd_hms(1900, 1, 1, 0)?, 1_000_000_000),
+        EpochFamily::JulianDay => (ymd_hms(-4713, 11, 24, 12)?, 86_400_000_000_000),
+        EpochFamily::ModifiedJulianDay => (ymd_hms(1858, 11, 17, 0)?, 86_400_000_000_000),
+    };
+
+    let nanos = mantissa
+        .checked_mul(nanos_per_unit)
+        .map(|scaled| scaled.div_euclid(10_i128.pow(scale)));
+    let dt = nanos.and_then(|nanos| {
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        let subsec = nanos.rem_euclid(1_000_000_000) as i64;
+        origin
+            .checked_add_signed(TimeDelta::try_seconds(secs)?)?
+            .checked_add_signed(TimeDelta::nanoseconds(subsec))
+    });
+    let Some(mut dt) = dt else {
+        return Some(Err(out_of_range()));
+    };
+
+    if family == EpochFamily::Gps {
+        // Find the offset whose start, expressed on the GPS time scale, has been reached.
+        let leap = GPS_LEAP_SECONDS
+            .iter()
+            .rev()
+            .find_map(|&(y, m, d, offset)| {
+                let start = ymd_hms(y, m, d, 0)? + TimeDelta::seconds(offset);
+                (dt >= start).then_some(offset)
+            })
+            .unwrap_or(0);
+        dt -= TimeDelta::seconds(leap);
+    }
+
+    Some(Ok(dt.and_utc()))
+}
+
+/// Parses a signed decimal number into an integer mantissa and a power-of-ten scale.
+///
+/// At most 15 fractional digits are kept, which is finer than a nanosecond for every
+/// [`EpochFamily`] unit.
+fn parse_decimal(s: &str) -> Option<(i128, u32)> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let frac_part = &frac_part[..frac_part.len().min(15)];
+    if int_part.is_empty()
+        || !(int_part.bytes().chain(frac_part.bytes())).all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let mut mantissa: i128 = int_part.parse().ok()?;
+    for digit in frac_part.bytes() {
+        mantissa = mantissa
+            .checked_mul(10)?
+            .checked_add(i128::from(digit - b'0'))?;
+    }
+    Some((
+        if negative { -mantissa } else { mantissa },
+        frac_part.len() as u32,
+    ))
+}
+
+fn ymd_hms(year: i32, month: u32, day: u32, hour: u32) -> Option<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(hour, 0, 0)
+}
+
+/// The largest serial date supported by spreadsheets: 9999-12-31 in the 1900 date system.
+const MAX_SERIAL_DATE: i64 = 2_958_465;
+
+/// Converts a spreadsheet serial date number such as `45078.4166667` to a naive local datetime.
+///
+/// The integer part counts days and the fraction gives the time of day, rounded to the
+/// nearest millisecond.
+///
+/// # Returns
+/// * `None` if `s` is not an unsigned decimal number within the spreadsheet range.
+/// * `Some(Ok(NaiveDateTime))` for a valid serial date.
+/// * `Some(Err(TimeParseError::InvalidFieldValue))` for serial `60` in the 1900 date system,
+///   which stands for the nonexistent 1900-02-29.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::datetime::serial_date_to_naive;
+/// use utcize::types::SerialDateSystem;
+///
+/// let dt = serial_date_to_naive("45078.5", SerialDateSystem::Excel1900).unwrap().unwrap();
+/// assert_eq!(dt.to_string(), "2023-06-01 12:00:00");
+///
+/// let dt = serial_date_to_naive("43616.5", SerialDateSystem::Excel1904).unwrap().unwrap();
+/// assert_eq!(dt.to_string(), "2023-06-01 12:00:00");
+/// ```
+pub fn serial_date_to_naive(
+    s: &str,
+    system: SerialDateSystem,
+) -> Option<Result<NaiveDateTime, TimeParseError>> {
+    let (int_part, frac_part) = s.split_once('.').unwrap_or((s, ""));
+    if int_part.is_empty()
+        || !(int_part.bytes().chain(frac_part.bytes())).all(|b| b.is_ascii_digit())
+    {
+        return None;
     }
+
+    let days: i64 = int_part
+        .parse()
+        .ok()
+        .filter(|days| *days <= MAX_SERIAL_DATE)?;
+    let fraction: f64 = format!("0.{}", frac_part).parse().ok()?;
+    let millis = (fraction * 86_400_000.0).round() as i64;
+
+    let base = match system {
+        SerialDateSystem::Excel1900 if days == 60 => {
+            return Some(Err(TimeParseError::InvalidFieldValue {
+                input: s.to_string(),
+                format: "Excel 1900 serial date".into(),
+                kind: ParseErrorKind::Impossible,
+            }));
+        }
+        // Serials before the fictitious 1900-02-29 are one day later than a plain count.
+        SerialDateSystem::Excel1900 if days < 60 => NaiveDate::from_ymd_opt(1899, 12, 31)?,
+        SerialDateSystem::Excel1900 => NaiveDate::from_ymd_opt(1899, 12, 30)?,
+        SerialDateSystem::Excel1904 => NaiveDate::from_ymd_opt(1904, 1, 1)?,
+    };
+
+    let dt = base.and_hms_opt(0, 0, 0)? + TimeDelta::days(days) + TimeDelta::milliseconds(millis);
+    Some(Ok(dt))
+}
+
+/// Parses a string of ASCII digits with an optional leading `+` or `-`.
+pub(crate) fn parse_epoch_value(s: &str) -> Option<i64> {
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
 }
 
 /// Parses a datetime string into a `DateTime<Utc>`, accepting a wide variety of formats.
 ///
 /// This function supports:
-/// - Unix epoch (seconds, milliseconds, microseconds, nanoseconds)
+/// - Unix epoch (seconds, milliseconds, microseconds, nanoseconds), optionally signed
+///   or with an explicit unit suffix (`s`, `ms`, `us`, `ns`)
 /// - ISO 8601, RFC 3339, RFC 2822
 /// - Flexible date/time formats (with optional European preference)
 /// - Fallback timezone if input has no timezone
@@ -45,6 +358,10 @@ pub fn detect_epoch_kind(s: &str) -> Option<EpochKind> {
 /// # Returns
 /// * `Ok(DateTime<Utc>)` - Normalized UTC datetime.
 /// * `Err(TimeParseError)` - If parsing fails or time is ambiguous.
+///
+/// Without custom formats this runs on a shared, lazily built [`Utcizer`]; custom formats are
+/// compiled into a one-off [`Utcizer`] on every call. When parsing many inputs with the same
+/// custom formats, build a [`Utcizer`] once and reuse it instead.
 pub fn utcize<S>(
     s: &str,
     fallback_tz: &str,
@@ -54,80 +371,79 @@ pub fn utcize<S>(
 where
     S: AsRef<str>,
 {
-    let s = s.trim();
-
-    // === Epoch numeric ===
-    if s.chars().all(|c| c.is_numeric()) {
-        if let Ok(num) = s.parse::<i64>() {
-            if let Some(kind) = detect_epoch_kind(s) {
-                let dt_opt = match kind {
-                    EpochKind::Seconds => Utc.timestamp_opt(num, 0).single(),
-                    EpochKind::Milliseconds => {
-                        let secs = num / 1000;
-                        let nsecs = ((num % 1000) * 1_000_000) as u32;
-                        Utc.timestamp_opt(secs, nsecs).single()
-                    }
-                    EpochKind::Microseconds => {
-                        let secs = num / 1_000_000;
-                        let nsecs = ((num % 1_000_000) * 1_000) as u32;
-                        Utc.timestamp_opt(secs, nsecs).single()
-                    }
-                    EpochKind::Nanoseconds => {
-                        let secs = num / 1_000_000_000;
-                        let nsecs = (num % 1_000_000_000) as u32;
-                        Utc.timestamp_opt(secs, nsecs).single()
-                    }
-                };
-
-                return dt_opt.ok_or_else(|| {
-                    TimeParseError::InvalidInput("Epoch out of valid range".into())
-                });
-            }
-        }
-    }
+    utcize_detailed(s, fallback_tz, prefer_eu, usr_custom_formats).map(|outcome| outcome.utc)
+}
 
-    // === RFC 3339 / 2822 ===
-    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-        return Ok(dt.with_timezone(&Utc));
-    }
-    if let Ok(dt) = DateTime::parse_from_rfc2822(s) {
-        return Ok(dt.with_timezone(&Utc));
+/// Parses a datetime string like [`utcize`], and reports how the input was interpreted.
+///
+/// The returned [`ParseOutcome`] carries the UTC instant together with the detector or
+/// format that matched, the original offset, whether `fallback_tz` was applied and the
+/// epoch unit, if any.
+///
+/// # Arguments
+/// Same as [`utcize`].
+///
+/// # Returns
+/// * `Ok(ParseOutcome)` - Normalized UTC datetime and its provenance.
+/// * `Err(TimeParseError)` - If parsing fails or time is ambiguous.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::datetime::utcize_detailed;
+/// use utcize::types::ParseSource;
+///
+/// let outcome = utcize_detailed::<&str>("2023-06-01 10:00:00", "Asia/Jakarta", false, None).unwrap();
+/// assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+/// assert_eq!(outcome.source, ParseSource::DefaultFormat("%Y-%m-%d %H:%M:%S".into()));
+/// assert!(outcome.used_fallback);
+/// ```
+pub fn utcize_detailed<S>(
+    s: &str,
+    fallback_tz: &str,
+    prefer_eu: bool,
+    usr_custom_formats: Option<&[S]>,
+) -> Result<ParseOutcome, TimeParseError>
+where
+    S: AsRef<str>,
+{
+    // Only parsed if the input turns out to be naive.
+    let fallback_tz = || parse_timezone_str(fallback_tz).map(Cow::Owned);
+    match usr_custom_formats {
+        None => shared_utcizer(prefer_eu).parse_detailed_in(s, fallback_tz),
+        Some(customs) => Utcizer::builder()
+            .date_order(date_order(prefer_eu))
+            .custom_formats(customs)
+            .build()
+            .parse_detailed_in(s, fallback_tz),
     }
+}
 
-    // === Custom / Flexible format ===
-    match parse_datetime_flexible(s, prefer_eu, usr_custom_formats)? {
-        ParsedDatetime::WithTimezone(dt) => Ok(dt),
-        ParsedDatetime::Naive(naive) => {
-            match parse_timezone_str(fallback_tz)? {
-                TimeZoneParsed::FixedOffset(offset) => {
-                    let dt = offset
-                        .from_local_datetime(&naive)
-                        .single()
-                        .or_else(|| Some(offset.from_utc_datetime(&naive)))
-                        .ok_or_else(|| {
-                            TimeParseError::InvalidInput("Failed to resolve datetime".into())
-                        })?;
-                    Ok(dt.with_timezone(&Utc))
-                }
-                TimeZoneParsed::Iana(tz) => match tz.from_local_datetime(&naive) {
-                    chrono::LocalResult::Single(dt) => Ok(dt.with_timezone(&Utc)),
-                    chrono::LocalResult::Ambiguous(a, b) => Err(TimeParseError::AmbiguousTime {
-                        datetime: naive,
-                        options: vec![a.with_timezone(&Utc), b.with_timezone(&Utc)],
-                    }),
-                    chrono::LocalResult::None => Err(TimeParseError::InvalidInput(format!(
-                        "Nonexistent local time due to DST: {} in {}",
-                        naive, tz
-                    ))),
-                },
-            }
-        }
+/// Returns the [`Utcizer`] with default settings and the date order of `prefer_eu`, built
+/// once per process and shared.
+fn shared_utcizer(prefer_eu: bool) -> &'static Utcizer {
+    static EU: OnceLock<Utcizer> = OnceLock::new();
+    static US: OnceLock<Utcizer> = OnceLock::new();
+
+    let cell = if prefer_eu { &EU } else { &US };
+    cell.get_or_init(|| Utcizer::builder().date_order(date_order(prefer_eu)).build())
+}
+
+fn date_order(prefer_eu: bool) -> DateOrder {
+    if prefer_eu {
+        DateOrder::DayFirst
+    } else {
+        DateOrder::MonthFirst
     }
 }
 
 /// Tries to parse a datetime string using custom and default formats.
 ///
-/// If the format includes timezone offset (e.g., `%z` or `%:z`), it returns a fully qualified UTC datetime.
+/// If the format includes timezone offset (e.g., `%z` or `%:z`), it returns a datetime in that offset.
+/// A leading or trailing IANA timezone such as `Asia/Jakarta` is resolved like a fallback
+/// timezone, reporting ambiguous or nonexistent local times as errors.
+/// A trailing timezone abbreviation such as `WIB` or `PDT` is resolved through the
+/// [abbreviation table](crate::abbrev); ambiguous abbreviations such as `IST` are reported as errors.
 /// If the format is naive (no timezone info), it returns a `NaiveDateTime` which requires a fallback.
 ///
 /// # Arguments
@@ -138,7 +454,14 @@ where
 /// # Returns
 /// * `Ok(ParsedDatetime::WithTimezone)` if the string includes timezone information.
 /// * `Ok(ParsedDatetime::Naive)` if timezone is missing and fallback is needed.
-/// * `Err(TimeParseError)` if no format matched.
+/// * `Err(TimeParseError::InvalidFieldValue)` if the closest format matched but a field is invalid.
+/// * `Err(TimeParseError::NoFormatMatched)` if no format matched.
+/// * `Err(TimeParseError::AmbiguousAbbreviation)` or `Err(TimeParseError::AbbreviationMismatch)`
+///   if the trailing abbreviation cannot be resolved or does not match the date.
+///
+/// Without custom formats this runs on a shared, lazily built [`Utcizer`]. Custom formats are
+/// compiled on every call; for repeated parsing prefer a
+/// [`CompiledFormats`](crate::formats::CompiledFormats) or a [`Utcizer`], which compile them once.
 pub fn parse_datetime_flexible<S>(
     s: &str,
     prefer_eu: bool,
@@ -147,33 +470,21 @@ pub fn parse_datetime_flexible<S>(
 where
     S: AsRef<str>,
 {
-    let mut formats: Vec<String> = vec![];
-
-    if let Some(customs) = custom_formats {
-        formats.extend(customs.iter().map(|s| s.as_ref().to_string()));
-    }
-
-    formats.extend(default_formats(prefer_eu).into_iter().map(|s| s.to_string()));
-
-    for fmt in formats {
-        let fmt_str = fmt.as_str();
-
-        if let Ok(dt) = DateTime::parse_from_str(s, fmt_str) {
-            return Ok(ParsedDatetime::WithTimezone(dt.with_timezone(&Utc)));
+    let one_off;
+    let utcizer = match custom_formats {
+        None => shared_utcizer(prefer_eu),
+        Some(customs) => {
+            one_off = Utcizer::builder()
+                .date_order(date_order(prefer_eu))
+                .custom_formats(customs)
+                .build();
+            &one_off
         }
+    };
 
-        if !fmt_str.contains("%z") && !fmt_str.contains("%:z") {
-            if let Ok(ndt) = NaiveDateTime::parse_from_str(s, fmt_str) {
-                return Ok(ParsedDatetime::Naive(ndt));
-            }
-
-            if let Ok(date) = NaiveDate::parse_from_str(s, fmt_str) {
-                if let Some(ndt) = date.and_hms_opt(0, 0, 0) {
-                    return Ok(ParsedDatetime::Naive(ndt));
-                }
-            }
-        }
+    let mut closest = ClosestMatch::default();
+    match utcizer.match_formats(s, &mut closest)? {
+        Some((parsed, _)) => Ok(parsed.into()),
+        None => Err(closest.into_error(s)),
     }
-
-    Err(TimeParseError::InvalidInput(format!("No matching format found for: '{}'", s)))
 }
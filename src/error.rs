@@ -1,16 +1,100 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
+use crate::types::EpochKind;
+use chrono::format::ParseErrorKind;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 use thiserror::Error;
 
 /// Represents errors that can occur while parsing or normalizing datetime input.
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq)]
 pub enum TimeParseError {
-    /// The input string could not be parsed into a valid datetime format.
-    ///
-    /// This can happen due to an invalid format, unrecognized timezone,
-    /// or out-of-range values.
+    /// The input could not be processed for a reason not covered by a more specific variant.
     #[error("invalid datetime input: {0}")]
     InvalidInput(String),
 
+    /// The timezone string looks like an IANA name but is not a known timezone.
+    #[error("Unknown IANA timezone '{name}'{}", did_you_mean(.suggestions))]
+    UnknownTimezone {
+        /// The timezone name as given.
+        name: String,
+
+        /// Known timezones with a similar spelling, closest first.
+        suggestions: Vec<String>,
+    },
+
+    /// A fixed offset is 24 hours or more, or has minutes or seconds of 60 or more.
+    #[error("Fixed offset out of range: '{input}'")]
+    OffsetOutOfRange {
+        /// The offset as given.
+        input: String,
+    },
+
+    /// The timezone string is neither a valid offset nor a well-formed IANA name.
+    #[error("Invalid IANA timezone format: '{name}'")]
+    MalformedTimezone {
+        /// The timezone string as given.
+        name: String,
+    },
+
+    /// The timezone name is a deprecated IANA link and deprecated links are rejected.
+    #[error("Deprecated IANA timezone '{name}', use '{replacement}' instead")]
+    DeprecatedTimezone {
+        /// The deprecated name.
+        name: String,
+
+        /// The current name of the timezone.
+        replacement: String,
+    },
+
+    /// The fixed UTC offset could not be parsed (e.g. `+7:0` or `+25:00`).
+    #[error("Invalid fixed offset format: '{input}'")]
+    MalformedOffset {
+        /// The offset string as given.
+        input: String,
+    },
+
+    /// The numeric epoch does not fit in the supported datetime range.
+    #[error("epoch out of valid range: {value} ({kind:?})")]
+    EpochOutOfRange {
+        /// The numeric epoch value.
+        value: i64,
+
+        /// The unit the value was interpreted in.
+        kind: EpochKind,
+    },
+
+    /// None of the formats tried matched the input.
+    #[error("No matching format found for: '{input}' ({tried} formats tried)")]
+    NoFormatMatched {
+        /// The input string.
+        input: String,
+
+        /// How many formats were tried.
+        tried: usize,
+
+        /// The chrono error of the format that came closest to matching, if any.
+        closest: Option<ParseErrorKind>,
+    },
+
+    /// The input matched the shape of a format, but a field holds an impossible value
+    /// (e.g. `2023-02-30` or a minute of `61`).
+    #[error("invalid field value in '{input}' for format '{format}': {kind:?}")]
+    InvalidFieldValue {
+        /// The input string.
+        input: String,
+
+        /// The format whose shape matched the input.
+        format: String,
+
+        /// The chrono error, typically `OutOfRange` or `Impossible`.
+        kind: ParseErrorKind,
+    },
+
+    /// A custom format string contains a specifier chrono does not understand.
+    #[error("Invalid format string: '{format}'")]
+    InvalidFormat {
+        /// The format string as given.
+        format: String,
+    },
+
     /// The input represents an ambiguous local time (typically during a daylight saving transition),
     /// resulting in two possible valid UTC datetimes.
     ///
@@ -23,4 +107,106 @@ pub enum TimeParseError {
         /// The two possible UTC interpretations.
         options: Vec<DateTime<Utc>>,
     },
+
+    /// The input represents a local time that does not exist in the timezone, because it falls
+    /// into a gap where clocks jump forward (typically at the start of daylight saving time).
+    #[error("nonexistent local time due to DST: {datetime} in {tz} (gap from {gap_start} to {gap_end})")]
+    NonexistentTime {
+        /// The nonexistent local datetime.
+        datetime: NaiveDateTime,
+
+        /// The name of the timezone.
+        tz: String,
+
+        /// The first local time skipped by the gap.
+        gap_start: NaiveDateTime,
+
+        /// The first valid local time after the gap.
+        gap_end: NaiveDateTime,
+    },
+
+    /// A local datetime given with an offset or abbreviation lies outside the range of
+    /// datetimes chrono supports once converted to UTC.
+    #[error("datetime out of range: {datetime} at offset {offset}")]
+    DatetimeOutOfRange {
+        /// The local datetime.
+        datetime: NaiveDateTime,
+
+        /// The offset it was given in.
+        offset: FixedOffset,
+    },
+
+    /// A timezone abbreviation has several meanings (e.g. `IST` for India, Ireland and Israel)
+    /// and no region preference selects one of them.
+    #[error("ambiguous timezone abbreviation '{abbreviation}', could be any of {candidates:?}")]
+    AmbiguousAbbreviation {
+        /// The ambiguous abbreviation.
+        abbreviation: String,
+
+        /// A representative IANA timezone for each meaning.
+        candidates: Vec<String>,
+    },
+
+    /// A daylight saving time abbreviation (e.g. `PDT`) is used on a date when its timezone
+    /// observes standard time.
+    #[error("daylight abbreviation '{abbreviation}' does not match {datetime}, when {zone} observes standard time")]
+    AbbreviationMismatch {
+        /// The daylight saving time abbreviation.
+        abbreviation: String,
+
+        /// The local datetime it was attached to.
+        datetime: NaiveDateTime,
+
+        /// The representative IANA timezone of the abbreviation.
+        zone: String,
+    },
+
+    /// A country code covers several timezones (e.g. `ID` for Indonesia) and no preference
+    /// selects one of them.
+    #[error("country '{country}' has several timezones, could be any of {candidates:?}")]
+    AmbiguousCountry {
+        /// The ISO 3166 country code.
+        country: String,
+
+        /// The IANA timezones used in the country, most representative first.
+        candidates: Vec<String>,
+    },
+
+    /// A syslog timestamp is the RFC 5424 NILVALUE `-`: the sender did not know the time.
+    #[error("syslog timestamp is NILVALUE '-', the sender did not know the time")]
+    NilTimestamp,
+
+    /// No format profile is registered under the name.
+    #[error("Unknown format profile '{name}'")]
+    UnknownProfile {
+        /// The profile name as given.
+        name: String,
+    },
+
+    /// A format profile extends itself, directly or through other profiles.
+    #[error("format profiles extend each other in a cycle: {}", chain.join(" -> "))]
+    ProfileCycle {
+        /// The profiles of the cycle, starting and ending with the same name.
+        chain: Vec<String>,
+    },
+
+    /// Timezone data from a [`TimeZoneProvider`](crate::provider::TimeZoneProvider) could not
+    /// be read or parsed.
+    #[error("invalid timezone data in '{origin}': {reason}")]
+    TimezoneData {
+        /// The file or line the data came from.
+        origin: String,
+
+        /// Why the data was rejected.
+        reason: String,
+    },
+}
+
+/// Formats timezone suggestions as a `, did you mean ...?` suffix.
+fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [only] => format!(", did you mean '{only}'?"),
+        _ => format!(", did you mean one of: {}?", suggestions.join(", ")),
+    }
 }
@@ -1,3 +1,11 @@
+use crate::error::TimeParseError;
+use crate::types::{ParsedDatetime, ParsedInput};
+use chrono::format::{
+    parse, parse_and_remainder, Fixed, Item, Numeric, ParseError, ParseErrorKind, ParseResult,
+    Parsed, StrftimeItems,
+};
+use std::sync::OnceLock;
+
 /// Returns a list of default datetime format strings for parsing.
 ///
 /// The formats returned depend on the `prefer_eu` flag:
@@ -35,9 +43,8 @@ pub fn default_formats(prefer_eu: bool) -> Vec<&'static str> {
             "%d/%m/%Y %H:%M",           // 01/06/2045 10:00
             "%d/%m/%Y",                 // 01/06/2045
             "%d.%m.%Y %H:%M:%S",        // 01.06.2045 10:00:00
+            "%d.%m.%Y %H:%M",           // 01.06.2045 10:00
             "%d.%m.%Y",                 // 01.06.2045
-            "%d %b %Y",                 // 01 Jun 2045
-            "%d %B %Y",                 // 01 June 2045
         ]);
     } else {
         formats.extend(vec![
@@ -68,7 +75,6 @@ pub fn default_formats(prefer_eu: bool) -> Vec<&'static str> {
         // ISO 8601 compact (no separators)
         "%Y%m%dT%H%M%S%z",               // 20230601T100000+0000
         "%Y%m%dT%H%M%S",                 // 20230601T100000
-        "%Y%m%d%H%M%S%Z",                // 20230601100000UTC
         "%Y%m%d%H%M%S",                  // 20230601100000
         // ISO week date
         "%G-W%V-%u",                     // 2023-W22-4 (ISO week date)
@@ -76,7 +82,363 @@ pub fn default_formats(prefer_eu: bool) -> Vec<&'static str> {
         // RFC 822 / 1123 / 2822 variants
         "%a, %d %b %Y %H:%M:%S %z",      // Thu, 01 Jun 2023 10:00:00 +0700
         "%d %b %Y %H:%M:%S %z",          // 01 Jun 2023 10:00:00 +0700
+        // Month and weekday names, also localized (see `crate::locale`)
+        "%d %b %Y",                      // 01 Jun 2045
+        "%d %B %Y",                      // 01 June 2045
+        "%d %B %Y %H:%M:%S",             // 01 June 2023 10:00:00
+        "%d %B %Y %H:%M",                // 01 June 2023 10:00
+        "%b %d %Y %H:%M:%S",             // Jun 1 2045 10:00:00
+        "%b %d %Y %H:%M",                // Jun 1 2045 10:00
+        "%A %d %B %Y",                   // Thursday 1 June 2023
+        "%A, %d %B %Y",                  // Thursday, 1 June 2023
+        "%A %d %B %Y %H:%M:%S",          // Thursday 1 June 2023 10:00:00
+        "%A, %B %d, %Y",                 // Thursday, June 1, 2023
     ]);
 
     formats
 }
+
+/// Returns the datetime formats of web server logs, used by the built-in `web-server-log`
+/// profile (see [`FormatProfile::web_server_log`](crate::registry::FormatProfile::web_server_log)).
+///
+/// Covers Apache and Nginx access logs (Common and Combined Log Format) and error logs, with
+/// or without the surrounding brackets. Fractional seconds are optional.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::formats::{web_server_log_formats, CompiledFormats};
+///
+/// let formats = CompiledFormats::new(web_server_log_formats());
+/// assert!(formats.parse("[10/Oct/2000:13:55:36 -0700]").is_some());
+/// assert!(formats.parse("[Thu Jun 01 10:00:00.123456 2023]").is_some());
+/// ```
+pub fn web_server_log_formats() -> Vec<&'static str> {
+    vec![
+        "[%d/%b/%Y:%H:%M:%S%.f %z]",     // [10/Oct/2000:13:55:36 -0700]
+        "%d/%b/%Y:%H:%M:%S%.f %z",       // 10/Oct/2000:13:55:36.123 -0700
+        "[%a %b %d %H:%M:%S%.f %Y]",     // [Thu Jun 01 10:00:00.123456 2023]
+        "%a %b %d %H:%M:%S%.f %Y",       // Thu Jun 01 10:00:00 2023
+        "%Y/%m/%d %H:%M:%S",             // 2023/06/01 10:00:00 (Nginx error log)
+    ]
+}
+
+/// A single datetime format string, pre-parsed into chrono [`Item`]s.
+///
+/// Tokenizing a strftime string is done once in [`CompiledFormat::new`]; every later call to
+/// [`CompiledFormat::parse`] reuses the items without allocating.
+#[derive(Debug, Clone)]
+pub struct CompiledFormat {
+    source: String,
+    items: Vec<Item<'static>>,
+    has_offset: bool,
+}
+
+impl CompiledFormat {
+    /// Compiles a strftime-style format string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError::InvalidFormat`] if the format contains an unknown specifier.
+    pub fn new(fmt: &str) -> Result<Self, TimeParseError> {
+        let items = StrftimeItems::new(fmt)
+            .parse_to_owned()
+            .map_err(|_| TimeParseError::InvalidFormat { format: fmt.to_string() })?;
+
+        Ok(Self {
+            source: fmt.to_string(),
+            items,
+            has_offset: fmt.contains("%z") || fmt.contains("%:z"),
+        })
+    }
+
+    /// Returns the original format string.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Parses `s` with this format.
+    ///
+    /// Formats with an offset (`%z` or `%:z`) yield [`ParsedDatetime::WithTimezone`].
+    /// Other formats yield [`ParsedDatetime::Naive`]; date-only formats resolve to midnight.
+    pub fn parse(&self, s: &str) -> ParseResult<ParsedDatetime> {
+        self.parse_input(s).map(ParsedDatetime::from)
+    }
+
+    /// Like [`parse`](Self::parse), keeping the offset found in the input.
+    pub(crate) fn parse_input(&self, s: &str) -> ParseResult<ParsedInput> {
+        self.parse_counting(s).map_err(|(e, _)| e)
+    }
+
+    /// Like [`parse_input`](Self::parse_input), also returning on failure how many bytes at
+    /// the end of `s` did not match the format (see [`parse_items`]).
+    pub(crate) fn parse_counting(&self, s: &str) -> Result<ParsedInput, (ParseError, usize)> {
+        let mut parsed = Parsed::new();
+        parse_items(&mut parsed, s, &self.items)?;
+        self.resolve(&parsed).map_err(|e| (e, 0))
+    }
+
+    /// Builds the datetime from the fields of a successful parse.
+    fn resolve(&self, parsed: &Parsed) -> ParseResult<ParsedInput> {
+        match parsed.to_datetime() {
+            Ok(dt) => return Ok(ParsedInput::WithOffset(dt)),
+            Err(e) if self.has_offset => return Err(e),
+            Err(_) => {}
+        }
+
+        match parsed.to_naive_datetime_with_offset(0) {
+            Ok(ndt) => Ok(ParsedInput::Naive(ndt)),
+            Err(e) => parsed
+                .to_naive_date()?
+                .and_hms_opt(0, 0, 0)
+                .map(ParsedInput::Naive)
+                .ok_or(e),
+        }
+    }
+}
+
+/// An ordered set of [`CompiledFormat`]s, tried first to last.
+///
+/// Build it once and reuse it across calls to avoid re-tokenizing format strings
+/// for every input.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::formats::CompiledFormats;
+/// use utcize::types::ParsedDatetime;
+///
+/// let formats = CompiledFormats::new(["%Y|%m|%d %H:%M"]);
+/// assert!(matches!(formats.parse("2023|06|01 10:00"), Some(ParsedDatetime::Naive(_))));
+///
+/// let defaults = CompiledFormats::defaults(true);
+/// assert!(defaults.parse("01.06.2023").is_some());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CompiledFormats {
+    formats: Vec<CompiledFormat>,
+}
+
+impl CompiledFormats {
+    /// Compiles a list of format strings.
+    ///
+    /// Format strings chrono cannot understand are skipped, since they could never match.
+    /// Use [`CompiledFormat::new`] to validate a single format up front.
+    pub fn new<I, S>(formats: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            formats: formats
+                .into_iter()
+                .filter_map(|fmt| CompiledFormat::new(fmt.as_ref()).ok())
+                .collect(),
+        }
+    }
+
+    /// Returns the compiled [`default_formats`], built once per process and shared.
+    pub fn defaults(prefer_eu: bool) -> &'static CompiledFormats {
+        static EU: OnceLock<CompiledFormats> = OnceLock::new();
+        static US: OnceLock<CompiledFormats> = OnceLock::new();
+
+        let cell = if prefer_eu { &EU } else { &US };
+        cell.get_or_init(|| CompiledFormats::new(default_formats(prefer_eu)))
+    }
+
+    /// Returns an iterator over the compiled formats, in the order they are tried.
+    pub fn iter(&self) -> impl Iterator<Item = &CompiledFormat> {
+        self.formats.iter()
+    }
+
+    /// Returns the number of compiled formats.
+    pub fn len(&self) -> usize {
+        self.formats.len()
+    }
+
+    /// Returns `true` if there are no compiled formats.
+    pub fn is_empty(&self) -> bool {
+        self.formats.is_empty()
+    }
+
+    /// Returns the first successful parse of `s`, or `None` if no format matches.
+    pub fn parse(&self, s: &str) -> Option<ParsedDatetime> {
+        self.parse_with_format(s).map(|(parsed, _)| parsed)
+    }
+
+    /// Like [`parse`](Self::parse), but also returns the format that matched.
+    pub fn parse_with_format(&self, s: &str) -> Option<(ParsedDatetime, &CompiledFormat)> {
+        self.parse_tracking(s, &mut ClosestMatch::default())
+            .map(|(parsed, fmt)| (parsed.into(), fmt))
+    }
+
+    /// Like [`parse_with_format`](Self::parse_with_format), recording failed attempts in `closest`.
+    pub(crate) fn parse_tracking(
+        &self,
+        s: &str,
+        closest: &mut ClosestMatch,
+    ) -> Option<(ParsedInput, &CompiledFormat)> {
+        for fmt in &self.formats {
+            match fmt.parse_counting(s) {
+                Ok(parsed) => return Some((parsed, fmt)),
+                Err((e, unmatched)) => closest.record(fmt.as_str(), e.kind(), unmatched),
+            }
+        }
+        None
+    }
+}
+
+/// Parses `s` with `items` into `parsed` like chrono's [`parse`], one item at a time, and on
+/// failure also returns how many bytes at the end of `s` did not match the format.
+///
+/// A number or offset that is out of range or inconsistent does not end the scan: its error is
+/// kept and the remaining items are matched against the rest of `s`. So only an input with the
+/// shape of the whole format fails with nothing left unmatched.
+pub(crate) fn parse_items<'a, I>(
+    parsed: &mut Parsed,
+    s: &str,
+    items: I,
+) -> Result<(), (ParseError, usize)>
+where
+    I: IntoIterator<Item = &'a Item<'a>>,
+{
+    let mut rest = s;
+    let mut value_error = None;
+    for item in items {
+        match parse_and_remainder(parsed, rest, std::iter::once(item)) {
+            Ok(remainder) => rest = remainder,
+            Err(e)
+                if matches!(e.kind(), ParseErrorKind::OutOfRange | ParseErrorKind::Impossible) =>
+            {
+                let Some(len) = field_len(item, rest) else {
+                    return Err((e, rest.len()));
+                };
+                value_error.get_or_insert(e);
+                rest = &rest[len..];
+            }
+            Err(e) => return Err((e, rest.len())),
+        }
+    }
+    // Parsing no items fails with `TooLong` if any input is left.
+    parse(&mut Parsed::new(), rest, std::iter::empty::<Item>()).map_err(|e| (e, rest.len()))?;
+    match value_error {
+        Some(e) => Err((e, 0)),
+        None => Ok(()),
+    }
+}
+
+/// Returns the length of the number or offset that `item` reads at the start of `s`, following
+/// chrono's scanning rules, or `None` for other items.
+fn field_len(item: &Item, s: &str) -> Option<usize> {
+    let trimmed = s.trim_start();
+    let (sign, width) = match item {
+        Item::Numeric(Numeric::Year | Numeric::IsoYear, _) if trimmed.starts_with(['+', '-']) => {
+            (1, usize::MAX)
+        }
+        Item::Numeric(numeric, _) => match numeric {
+            Numeric::Year | Numeric::IsoYear => (0, 4),
+            Numeric::Quarter | Numeric::NumDaysFromSun | Numeric::WeekdayFromMon => (0, 1),
+            Numeric::Ordinal => (0, 3),
+            Numeric::Nanosecond => (0, 9),
+            Numeric::Timestamp => (0, usize::MAX),
+            _ => (0, 2),
+        },
+        Item::Fixed(
+            Fixed::TimezoneOffset
+            | Fixed::TimezoneOffsetColon
+            | Fixed::TimezoneOffsetZ
+            | Fixed::TimezoneOffsetColonZ,
+        ) => {
+            let body = trimmed.strip_prefix(['+', '-'])?;
+            let rest = body.trim_start_matches(|c: char| c.is_ascii_digit() || c == ':');
+            return Some(s.len() - rest.len());
+        }
+        _ => return None,
+    };
+    let digits = trimmed[sign..]
+        .bytes()
+        .take(width)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    Some(s.len() - trimmed.len() + sign + digits)
+}
+
+/// Returns how many bytes at the end of `s` the fixed RFC 2822 or RFC 3339 item does not
+/// match, for recording a failed [`DateTime::parse_from_rfc2822`](chrono::DateTime::parse_from_rfc2822)
+/// or [`DateTime::parse_from_rfc3339`](chrono::DateTime::parse_from_rfc3339) attempt.
+pub(crate) fn unmatched_by(s: &str, fixed: Fixed) -> usize {
+    match parse_items(&mut Parsed::new(), s, &[Item::Fixed(fixed)]) {
+        Ok(()) => 0,
+        Err((_, unmatched)) => unmatched,
+    }
+}
+
+/// Tracks failed parse attempts and remembers the one that came closest to matching.
+#[derive(Debug, Default)]
+pub(crate) struct ClosestMatch {
+    tried: usize,
+    best: Option<Attempt>,
+}
+
+/// A failed parse attempt.
+#[derive(Debug)]
+struct Attempt {
+    format: String,
+    kind: ParseErrorKind,
+    /// How many bytes at the end of the input the format did not match.
+    unmatched: usize,
+}
+
+impl Attempt {
+    /// Ranks the attempt; higher is closer to a match.
+    fn rank(&self) -> (std::cmp::Reverse<usize>, u8) {
+        (std::cmp::Reverse(self.unmatched), closeness(self.kind))
+    }
+}
+
+impl ClosestMatch {
+    /// Records a failed attempt with `format`, which left `unmatched` bytes at the end of the
+    /// input unmatched.
+    pub(crate) fn record(&mut self, format: &str, kind: ParseErrorKind, unmatched: usize) {
+        self.tried += 1;
+        let attempt = Attempt { format: format.to_string(), kind, unmatched };
+        if self.best.as_ref().is_none_or(|best| attempt.rank() > best.rank()) {
+            self.best = Some(attempt);
+        }
+    }
+
+    /// Converts the attempts into the most specific error for `input`.
+    ///
+    /// If the closest attempt matched the whole input and only failed on a field value, the
+    /// input had the right shape, so [`TimeParseError::InvalidFieldValue`] is returned instead
+    /// of [`TimeParseError::NoFormatMatched`].
+    pub(crate) fn into_error(self, input: &str) -> TimeParseError {
+        match self.best {
+            Some(Attempt {
+                format,
+                kind: kind @ (ParseErrorKind::OutOfRange | ParseErrorKind::Impossible),
+                unmatched: 0,
+            }) => TimeParseError::InvalidFieldValue { input: input.to_string(), format, kind },
+            best => TimeParseError::NoFormatMatched {
+                input: input.to_string(),
+                tried: self.tried,
+                closest: best.map(|attempt| attempt.kind),
+            },
+        }
+    }
+}
+
+/// Ranks failures with `kind` among attempts that matched equally much of the input; higher
+/// is closer to a match.
+fn closeness(kind: ParseErrorKind) -> u8 {
+    match kind {
+        // Only a field value was wrong.
+        ParseErrorKind::OutOfRange | ParseErrorKind::Impossible => 4,
+        // Some fields were missing.
+        ParseErrorKind::NotEnough => 3,
+        // The format matched, but input was left over.
+        ParseErrorKind::TooLong => 2,
+        ParseErrorKind::Invalid | ParseErrorKind::TooShort => 1,
+        _ => 0,
+    }
+}
@@ -0,0 +1,268 @@
+use crate::error::TimeParseError;
+use crate::formats::ClosestMatch;
+use crate::types::{ParsedDatetime, ParsedInput};
+use chrono::format::ParseErrorKind;
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Weekday};
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// Parses an ISO 8601-1 date or date and time, in basic or extended format.
+///
+/// Supported forms:
+/// - Calendar dates: `2023-06-01`, `20230601`, and reduced to `2023-06` or `2023`.
+/// - Ordinal dates: `2023-152`, `2023152`.
+/// - Week dates: `2023-W22-4`, `2023W224`, and reduced to `2023-W22`.
+/// - Times after `T`, reduced to hours or minutes: `T10`, `T10:00`, `T1000`, `T10:00:00`.
+/// - A decimal fraction, with `.` or `,`, on the last time component: `T10:00:00,5`,
+///   `T10:30.5` (30.5 minutes), `T10.5` (10.5 hours).
+/// - The end of the day `T24:00:00`, which is midnight of the following day.
+/// - A leap second `T23:59:60`.
+/// - A UTC designator or offset after the time: `Z`, `+07`, `+0700`, `+07:00`.
+///
+/// Reduced dates and dates without a time start at midnight.
+///
+/// # Returns
+/// * `Ok(ParsedDatetime::WithTimezone)` if the time has a UTC designator or offset.
+/// * `Ok(ParsedDatetime::Naive)` otherwise.
+/// * `Err(TimeParseError::InvalidFieldValue)` if a field is out of range (e.g. `2023-366`).
+/// * `Err(TimeParseError::NoFormatMatched)` if `s` is not ISO 8601.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::iso8601::parse_iso8601;
+/// use utcize::types::ParsedDatetime;
+///
+/// match parse_iso8601("2023-152T10,5").unwrap() {
+///     ParsedDatetime::Naive(dt) => assert_eq!(dt.to_string(), "2023-06-01 10:30:00"),
+///     other => panic!("Expected Naive, got {other:?}"),
+/// }
+///
+/// match parse_iso8601("2023-06-01T24:00Z").unwrap() {
+///     ParsedDatetime::WithTimezone(dt) => assert_eq!(dt.to_rfc3339(), "2023-06-02T00:00:00+00:00"),
+///     other => panic!("Expected WithTimezone, got {other:?}"),
+/// }
+/// ```
+pub fn parse_iso8601(s: &str) -> Result<ParsedDatetime, TimeParseError> {
+    let mut closest = ClosestMatch::default();
+    parse_iso8601_tracking(s, &mut closest)
+        .map(ParsedDatetime::from)
+        .ok_or_else(|| closest.into_error(s))
+}
+
+/// Like [`parse_iso8601`], recording a failed attempt in `closest`.
+pub(crate) fn parse_iso8601_tracking(s: &str, closest: &mut ClosestMatch) -> Option<ParsedInput> {
+    match parse_iso8601_inner(s) {
+        Ok(parsed) => Some(parsed),
+        Err(kind) => {
+            // Values are only checked once the whole input has the shape of ISO 8601.
+            let unmatched = if kind == ParseErrorKind::Invalid {
+                s.len()
+            } else {
+                0
+            };
+            closest.record("ISO 8601", kind, unmatched);
+            None
+        }
+    }
+}
+
+fn parse_iso8601_inner(s: &str) -> Result<ParsedInput, ParseErrorKind> {
+    let (date, time) = match s.split_once(['T', 't']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+
+    let (date, complete) = parse_date(date)?;
+    let Some(time) = time else {
+        let date = date.ok_or(ParseErrorKind::OutOfRange)?;
+        return Ok(ParsedInput::Naive(date.and_time(NaiveTime::MIN)));
+    };
+    // A time may only follow a complete date.
+    if !complete {
+        return Err(ParseErrorKind::Invalid);
+    }
+
+    let (time, offset) = split_offset(time)?;
+    let naive = match date {
+        Some(date) => parse_time(date, time)?,
+        // Check the shape of the time before reporting the date.
+        None => {
+            parse_time(NaiveDate::default(), time)?;
+            return Err(ParseErrorKind::OutOfRange);
+        }
+    };
+    match offset.transpose()? {
+        None => Ok(ParsedInput::Naive(naive)),
+        Some(offset) => offset
+            .from_local_datetime(&naive)
+            .single()
+            .map(ParsedInput::WithOffset)
+            .ok_or(ParseErrorKind::Impossible),
+    }
+}
+
+/// Parses a calendar, ordinal or week date, returning the date, or `None` if a field is out of
+/// range, and whether it is complete.
+fn parse_date(s: &str) -> Result<(Option<NaiveDate>, bool), ParseErrorKind> {
+    let date = if shape(s, "dddd-dd-dd") {
+        (
+            NaiveDate::from_ymd_opt(num(s, 0..4), num(s, 5..7), num(s, 8..10)),
+            true,
+        )
+    } else if shape(s, "dddddddd") {
+        (
+            NaiveDate::from_ymd_opt(num(s, 0..4), num(s, 4..6), num(s, 6..8)),
+            true,
+        )
+    } else if shape(s, "dddd-dd") {
+        (
+            NaiveDate::from_ymd_opt(num(s, 0..4), num(s, 5..7), 1),
+            false,
+        )
+    } else if shape(s, "dddd") {
+        (NaiveDate::from_ymd_opt(num(s, 0..4), 1, 1), false)
+    } else if shape(s, "dddd-ddd") {
+        (NaiveDate::from_yo_opt(num(s, 0..4), num(s, 5..8)), true)
+    } else if shape(s, "ddddddd") {
+        (NaiveDate::from_yo_opt(num(s, 0..4), num(s, 4..7)), true)
+    } else if shape(s, "dddd-Wdd-d") {
+        (week_date(num(s, 0..4), num(s, 6..8), num(s, 9..10)), true)
+    } else if shape(s, "ddddWddd") {
+        (week_date(num(s, 0..4), num(s, 5..7), num(s, 7..8)), true)
+    } else if shape(s, "dddd-Wdd") {
+        (week_date(num(s, 0..4), num(s, 6..8), 1), false)
+    } else if shape(s, "ddddWdd") {
+        (week_date(num(s, 0..4), num(s, 5..7), 1), false)
+    } else {
+        return Err(ParseErrorKind::Invalid);
+    };
+
+    Ok(date)
+}
+
+/// Returns the date of ISO week `week` of `year`, with `day` from 1 (Monday) to 7 (Sunday).
+fn week_date(year: i32, week: u32, day: u32) -> Option<NaiveDate> {
+    let weekday = Weekday::try_from(u8::try_from(day.checked_sub(1)?).ok()?).ok()?;
+    NaiveDate::from_isoywd_opt(year, week, weekday)
+}
+
+/// An offset, or why its value is out of range.
+type Offset = Result<FixedOffset, ParseErrorKind>;
+
+/// Splits a trailing UTC designator or offset (`Z`, `+07`, `+0700`, `+07:00`) off a time.
+///
+/// An offset out of range is returned as an error inside the result, so that the shape of the
+/// time can still be checked.
+fn split_offset(s: &str) -> Result<(&str, Option<Offset>), ParseErrorKind> {
+    if let Some(time) = s.strip_suffix(['Z', 'z']) {
+        return Ok((time, FixedOffset::east_opt(0).map(Ok)));
+    }
+    let Some(sign_at) = s.find(['+', '-']) else {
+        return Ok((s, None));
+    };
+
+    let (time, offset) = s.split_at(sign_at);
+    let digits = &offset[1..];
+    let (hours, minutes): (i32, i32) = if shape(digits, "dd") {
+        (num(digits, 0..2), 0)
+    } else if shape(digits, "dddd") {
+        (num(digits, 0..2), num(digits, 2..4))
+    } else if shape(digits, "dd:dd") {
+        (num(digits, 0..2), num(digits, 3..5))
+    } else {
+        return Err(ParseErrorKind::Invalid);
+    };
+    if hours >= 24 || minutes >= 60 {
+        return Ok((time, Some(Err(ParseErrorKind::OutOfRange))));
+    }
+
+    let seconds = hours * 3600 + minutes * 60;
+    let offset = if offset.starts_with('-') {
+        FixedOffset::west_opt(seconds)
+    } else {
+        FixedOffset::east_opt(seconds)
+    };
+    Ok((time, offset.map(Ok)))
+}
+
+/// Parses a time of day, possibly reduced or with a decimal fraction, on `date`.
+fn parse_time(date: NaiveDate, s: &str) -> Result<NaiveDateTime, ParseErrorKind> {
+    let (whole, fraction) = match s.split_once(['.', ',']) {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (s, None),
+    };
+
+    let (hour, minute, second, unit): (u32, u32, u32, i64) = if shape(whole, "dd") {
+        (num(whole, 0..2), 0, 0, 3600)
+    } else if shape(whole, "dd:dd") {
+        (num(whole, 0..2), num(whole, 3..5), 0, 60)
+    } else if shape(whole, "dddd") {
+        (num(whole, 0..2), num(whole, 2..4), 0, 60)
+    } else if shape(whole, "dd:dd:dd") {
+        (num(whole, 0..2), num(whole, 3..5), num(whole, 6..8), 1)
+    } else if shape(whole, "dddddd") {
+        (num(whole, 0..2), num(whole, 2..4), num(whole, 4..6), 1)
+    } else {
+        return Err(ParseErrorKind::Invalid);
+    };
+
+    let fraction_nanos = match fraction {
+        Some(digits) => fraction_nanos(digits, unit).ok_or(ParseErrorKind::Invalid)?,
+        None => 0,
+    };
+
+    let time = match (hour, minute, second) {
+        // The end of the day is the start of the next one.
+        (24, 0, 0) if fraction_nanos == 0 => {
+            return date
+                .and_time(NaiveTime::MIN)
+                .checked_add_signed(TimeDelta::days(1))
+                .ok_or(ParseErrorKind::OutOfRange);
+        }
+        // A leap second, represented as chrono does.
+        (_, _, 60) => NaiveTime::from_hms_nano_opt(
+            hour,
+            minute,
+            59,
+            (NANOS_PER_SECOND + fraction_nanos) as u32,
+        ),
+        _ if hour < 24 && minute < 60 && second < 60 => {
+            let seconds = i64::from(hour * 3600 + minute * 60 + second);
+            Some(
+                NaiveTime::MIN
+                    + TimeDelta::nanoseconds(seconds * NANOS_PER_SECOND + fraction_nanos),
+            )
+        }
+        _ => None,
+    };
+    time.map(|time| date.and_time(time))
+        .ok_or(ParseErrorKind::OutOfRange)
+}
+
+/// Converts the digits of a decimal fraction of `unit` seconds to nanoseconds, truncating.
+fn fraction_nanos(digits: &str, unit: i64) -> Option<i64> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // More digits than this cannot change the result in nanoseconds.
+    let digits = &digits[..digits.len().min(15)];
+    let numerator: i128 = digits.parse().ok()?;
+    let denominator = 10i128.pow(digits.len() as u32);
+    i64::try_from(numerator * i128::from(unit * NANOS_PER_SECOND) / denominator).ok()
+}
+
+/// Returns `true` if `s` matches `pattern`, where `d` stands for an ASCII digit and every other
+/// character for itself.
+fn shape(s: &str, pattern: &str) -> bool {
+    s.len() == pattern.len()
+        && s.bytes().zip(pattern.bytes()).all(|(c, p)| match p {
+            b'd' => c.is_ascii_digit(),
+            _ => c == p,
+        })
+}
+
+/// Parses the ASCII digits of `s` in `range`, which [`shape`] has already checked.
+fn num<T: std::str::FromStr + Default>(s: &str, range: std::ops::Range<usize>) -> T {
+    s[range].parse().unwrap_or_default()
+}
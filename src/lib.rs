@@ -7,11 +7,21 @@
 //! ## Features
 //!
 //! - Automatic format detection (RFC 3339, RFC 2822, ISO 8601, Unix timestamps: seconds, milliseconds, microseconds, nanoseconds).
+//! - Full ISO 8601-1 grammar: ordinal and week dates, reduced precision (`2023-06-01T10`), comma fractions, fractional hours and `24:00`.
 //! - Supports both European-style (`dd-mm-yyyy`) and US-style (`mm-dd-yyyy`) formats.
+//! - Month and weekday names in Indonesian, German, French, Spanish, Dutch, Portuguese and Italian (e.g., `01 Mei 2023 10:00`, `1. März 2023`).
 //! - Handles fixed timezone offsets (e.g., `+07:00`, `-0800`) and IANA timezones (e.g., `Asia/Jakarta`, `Europe/Berlin`).
+//! - Resolves timezone abbreviations (e.g., `WIB`, `PDT`, `CEST`), with a region preference for ambiguous ones such as `IST`.
+//! - Maps Windows time zone names (e.g., `W. Europe Standard Time`) to IANA timezones and back.
+//! - Resolves ISO 3166 country codes (e.g., `JP`, `ID`) to IANA timezones, with a preference for countries with several.
+//! - Infers candidate IANA timezones from a fixed offset and an instant, ranked by population or a country hint.
+//! - Pluggable timezone data: the bundled tz database, the system's `/usr/share/zoneinfo`, or raw tzdata files.
+//! - POSIX TZ rule strings (e.g., `CET-1CEST,M3.5.0,M10.5.0/3`, `WIB-7`) as fallback timezones.
 //! - Fallback timezone support for naive datetime strings (without timezone).
 //! - Ambiguity handling for local times during daylight saving transitions.
-//! - Easy to extend with custom formats.
+//! - Easy to extend with custom formats, or with named, composable format profiles in a runtime registry.
+//! - Built-in profile for Apache and Nginx log timestamps (e.g., `[10/Oct/2000:13:55:36 -0700]`).
+//! - Syslog mode for RFC 3164 (`Jun  1 10:00:00`, with year inference) and RFC 5424 timestamps.
 //!
 //! ## Example
 //!
@@ -39,6 +49,47 @@
 //!  assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00")
 //! ```
 //! 
+//! ## Reusable parser
+//!
+//! When parsing many inputs with the same settings, build a [`Utcizer`](utcizer::Utcizer) once.
+//! It is `Send + Sync`, so one instance can be shared between worker threads behind an `Arc`.
+//!
+//! ```rust
+//!  use std::sync::Arc;
+//!  use utcize::tz::parse_timezone_str;
+//!  use utcize::types::DateOrder;
+//!  use utcize::utcizer::Utcizer;
+//!
+//!  let utcizer = Arc::new(
+//!      Utcizer::builder()
+//!          .fallback_tz(parse_timezone_str("Europe/Berlin").unwrap())
+//!          .date_order(DateOrder::DayFirst)
+//!          .custom_formats(["%d.%B.%Y %H:%M"])
+//!          .build(),
+//!  );
+//!
+//!  let dt = utcizer.parse("01.June.2023 10:00").unwrap();
+//!  assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+//! ```
+//!
+//! ---
+//! 
+//! ## Upgrading from 0.1
+//!
+//! [`TimeZoneParsed::Iana`](types::TimeZoneParsed::Iana) now holds an [`IanaZone`](provider::IanaZone) instead of a `chrono_tz::Tz`, so that zones can come from any timezone provider, and the new `TimeZoneParsed::Posix` variant holds POSIX TZ rule strings.
+//! Code that matches on `TimeZoneParsed` has to be updated, so these changes ship in 0.2.0.
+//! [`IanaZone::as_tz()`](provider::IanaZone::as_tz) returns the bundled `Tz` of the same name:
+//!
+//! ```rust
+//!  use utcize::tz::parse_timezone_str;
+//!  use utcize::types::TimeZoneParsed;
+//!
+//!  match parse_timezone_str("Europe/Berlin").unwrap() {
+//!      TimeZoneParsed::Iana(zone) => assert_eq!(zone.as_tz(), Some(chrono_tz::Europe::Berlin)),
+//!      other => panic!("Expected Iana, got {other:?}"),
+//!  }
+//! ```
+//!
 //! ---
 //! 
 //! ## License
@@ -72,5 +123,35 @@ pub mod types;
 /// Collection of datetime format strings used for flexible parsing.
 pub mod formats;
 
+/// ISO 8601-1 dates and times: ordinal and week dates, reduced precision, decimal fractions.
+pub mod iso8601;
+
+/// Month and weekday names in other languages (e.g. `Juni`, `Mei`, `mercredi`) for non-English input.
+pub mod locale;
+
 /// Functions for parsing and validating fixed and IANA timezones.
 pub mod tz;
+
+/// Timezone abbreviations (e.g. `WIB`, `PDT`, `CEST`) and their resolution.
+pub mod abbrev;
+
+/// Windows time zone names (e.g. `SE Asia Standard Time`) mapped to IANA timezones.
+pub mod windows;
+
+/// ISO 3166 country codes (e.g. `ID`, `DE`) mapped to IANA timezones.
+pub mod country;
+
+/// Pluggable sources of IANA timezone rules: bundled, TZif directories and tzdata sources.
+pub mod provider;
+
+/// POSIX TZ rule strings (e.g. `CET-1CEST,M3.5.0,M10.5.0/3`) as rule-based timezones.
+pub mod posix;
+
+/// Reusable, pre-configured parser built once and shared across calls.
+pub mod utcizer;
+
+/// Syslog timestamps: RFC 3164 (`Jun  1 10:00:00`) and RFC 5424 (`2023-06-01T10:00:00+07:00`).
+pub mod syslog;
+
+/// Named format profiles and a runtime registry to select them by name.
+pub mod registry;
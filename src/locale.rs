@@ -0,0 +1,491 @@
+use chrono::{Month, Weekday};
+
+/// A language whose month and weekday names are recognised in datetime input.
+///
+/// chrono only knows English names, so localized names such as `Juni`, `Mei` or `mercredi`
+/// are translated to English before the formats are tried; see [`translate_names`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// Indonesian (`id`), e.g. `01 Mei 2023`.
+    Indonesian,
+    /// German (`de`), e.g. `1. März 2023`.
+    German,
+    /// French (`fr`), e.g. `1er juin 2023`.
+    French,
+    /// Spanish (`es`), e.g. `1 de junio de 2023`.
+    Spanish,
+    /// Dutch (`nl`), e.g. `1 juni 2023`.
+    Dutch,
+    /// Portuguese (`pt`), e.g. `1 de junho de 2023`.
+    Portuguese,
+    /// Italian (`it`), e.g. `1 giugno 2023`.
+    Italian,
+}
+
+impl Locale {
+    /// Every supported locale, in the order they are consulted by default.
+    pub const ALL: [Locale; 7] = [
+        Locale::Indonesian,
+        Locale::German,
+        Locale::French,
+        Locale::Spanish,
+        Locale::Dutch,
+        Locale::Portuguese,
+        Locale::Italian,
+    ];
+
+    /// Returns the ISO 639-1 code of the language, e.g. `de`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::Indonesian => "id",
+            Locale::German => "de",
+            Locale::French => "fr",
+            Locale::Spanish => "es",
+            Locale::Dutch => "nl",
+            Locale::Portuguese => "pt",
+            Locale::Italian => "it",
+        }
+    }
+
+    /// Returns the locale of an ISO 639-1 code such as `de`, ignoring case.
+    pub fn from_code(code: &str) -> Option<Locale> {
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(code))
+    }
+
+    fn names(&self) -> &'static Names {
+        match self {
+            Locale::Indonesian => &INDONESIAN,
+            Locale::German => &GERMAN,
+            Locale::French => &FRENCH,
+            Locale::Spanish => &SPANISH,
+            Locale::Dutch => &DUTCH,
+            Locale::Portuguese => &PORTUGUESE,
+            Locale::Italian => &ITALIAN,
+        }
+    }
+}
+
+/// The month and weekday names of a language, lowercase and without diacritics.
+/// Each entry lists the full name first, followed by abbreviations and variants.
+struct Names {
+    months: [&'static [&'static str]; 12],
+    weekdays: [&'static [&'static str]; 7],
+}
+
+const ENGLISH: Names = Names {
+    months: [
+        &["january", "jan"],
+        &["february", "feb"],
+        &["march", "mar"],
+        &["april", "apr"],
+        &["may"],
+        &["june", "jun"],
+        &["july", "jul"],
+        &["august", "aug"],
+        &["september", "sep", "sept"],
+        &["october", "oct"],
+        &["november", "nov"],
+        &["december", "dec"],
+    ],
+    weekdays: [
+        &["monday", "mon"],
+        &["tuesday", "tue"],
+        &["wednesday", "wed"],
+        &["thursday", "thu"],
+        &["friday", "fri"],
+        &["saturday", "sat"],
+        &["sunday", "sun"],
+    ],
+};
+
+const INDONESIAN: Names = Names {
+    months: [
+        &["januari", "jan"],
+        &["februari", "feb", "peb"],
+        &["maret", "mar"],
+        &["april", "apr"],
+        &["mei"],
+        &["juni", "jun"],
+        &["juli", "jul"],
+        &["agustus", "agu", "agt", "ags"],
+        &["september", "sep"],
+        &["oktober", "okt"],
+        &["november", "nov", "nop"],
+        &["desember", "des"],
+    ],
+    weekdays: [
+        &["senin", "sen"],
+        &["selasa", "sel"],
+        &["rabu", "rab"],
+        &["kamis", "kam"],
+        &["jumat", "jum"],
+        &["sabtu", "sab"],
+        &["minggu", "min"],
+    ],
+};
+
+const GERMAN: Names = Names {
+    months: [
+        &["januar", "jan", "janner"],
+        &["februar", "feb"],
+        &["marz", "mar", "maerz"],
+        &["april", "apr"],
+        &["mai"],
+        &["juni", "jun"],
+        &["juli", "jul"],
+        &["august", "aug"],
+        &["september", "sep", "sept"],
+        &["oktober", "okt"],
+        &["november", "nov"],
+        &["dezember", "dez"],
+    ],
+    weekdays: [
+        &["montag", "mo"],
+        &["dienstag", "di"],
+        &["mittwoch", "mi"],
+        &["donnerstag", "do"],
+        &["freitag", "fr"],
+        &["samstag", "sa", "sonnabend"],
+        &["sonntag", "so"],
+    ],
+};
+
+const FRENCH: Names = Names {
+    months: [
+        &["janvier", "janv"],
+        &["fevrier", "fevr", "fev"],
+        &["mars"],
+        &["avril", "avr"],
+        &["mai"],
+        &["juin"],
+        &["juillet", "juil"],
+        &["aout"],
+        &["septembre", "sept"],
+        &["octobre", "oct"],
+        &["novembre", "nov"],
+        &["decembre", "dec"],
+    ],
+    weekdays: [
+        &["lundi", "lun"],
+        &["mardi", "mar"],
+        &["mercredi", "mer"],
+        &["jeudi", "jeu"],
+        &["vendredi", "ven"],
+        &["samedi", "sam"],
+        &["dimanche", "dim"],
+    ],
+};
+
+const SPANISH: Names = Names {
+    months: [
+        &["enero", "ene"],
+        &["febrero", "feb"],
+        &["marzo", "mar"],
+        &["abril", "abr"],
+        &["mayo", "may"],
+        &["junio", "jun"],
+        &["julio", "jul"],
+        &["agosto", "ago"],
+        &["septiembre", "sep", "sept", "setiembre", "set"],
+        &["octubre", "oct"],
+        &["noviembre", "nov"],
+        &["diciembre", "dic"],
+    ],
+    weekdays: [
+        &["lunes", "lun"],
+        &["martes", "mar"],
+        &["miercoles", "mie"],
+        &["jueves", "jue"],
+        &["viernes", "vie"],
+        &["sabado", "sab"],
+        &["domingo", "dom"],
+    ],
+};
+
+const DUTCH: Names = Names {
+    months: [
+        &["januari", "jan"],
+        &["februari", "feb"],
+        &["maart", "mrt"],
+        &["april", "apr"],
+        &["mei"],
+        &["juni", "jun"],
+        &["juli", "jul"],
+        &["augustus", "aug"],
+        &["september", "sep", "sept"],
+        &["oktober", "okt"],
+        &["november", "nov"],
+        &["december", "dec"],
+    ],
+    weekdays: [
+        &["maandag", "ma"],
+        &["dinsdag", "di"],
+        &["woensdag", "wo"],
+        &["donderdag", "do"],
+        &["vrijdag", "vr"],
+        &["zaterdag", "za"],
+        &["zondag", "zo"],
+    ],
+};
+
+const PORTUGUESE: Names = Names {
+    months: [
+        &["janeiro", "jan"],
+        &["fevereiro", "fev"],
+        &["marco", "mar"],
+        &["abril", "abr"],
+        &["maio", "mai"],
+        &["junho", "jun"],
+        &["julho", "jul"],
+        &["agosto", "ago"],
+        &["setembro", "set"],
+        &["outubro", "out"],
+        &["novembro", "nov"],
+        &["dezembro", "dez"],
+    ],
+    weekdays: [
+        &["segunda", "seg"],
+        &["terca", "ter"],
+        &["quarta", "qua"],
+        &["quinta", "qui"],
+        &["sexta", "sex"],
+        &["sabado", "sab"],
+        &["domingo", "dom"],
+    ],
+};
+
+const ITALIAN: Names = Names {
+    months: [
+        &["gennaio", "gen"],
+        &["febbraio", "feb"],
+        &["marzo", "mar"],
+        &["aprile", "apr"],
+        &["maggio", "mag"],
+        &["giugno", "giu"],
+        &["luglio", "lug"],
+        &["agosto", "ago"],
+        &["settembre", "set"],
+        &["ottobre", "ott"],
+        &["novembre", "nov"],
+        &["dicembre", "dic"],
+    ],
+    weekdays: [
+        &["lunedi", "lun"],
+        &["martedi", "mar"],
+        &["mercoledi", "mer"],
+        &["giovedi", "gio"],
+        &["venerdi", "ven"],
+        &["sabato", "sab"],
+        &["domenica", "dom"],
+    ],
+};
+
+/// Words joining the day, month and year, as in `1 de junio de 2023`.
+const CONNECTORS: [&str; 2] = ["de", "del"];
+
+/// Ordinal suffixes of the day, as in `1er juin` or `1º de junho`.
+const ORDINAL_SUFFIXES: [&str; 3] = ["er", "º", "ª"];
+
+/// Looks up a month by its name or abbreviation in English or any of `locales`, ignoring case
+/// and diacritics.
+///
+/// English names take precedence, then the locales in the given order.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Month;
+/// use utcize::locale::{Locale, lookup_month};
+///
+/// assert_eq!(lookup_month("März", &Locale::ALL), Some(Month::March));
+/// assert_eq!(lookup_month("FEVR", &[Locale::French]), Some(Month::February));
+/// assert_eq!(lookup_month("Mei", &[Locale::German]), None);
+/// ```
+pub fn lookup_month(name: &str, locales: &[Locale]) -> Option<Month> {
+    let folded = fold(name);
+    lookup(&folded, locales, |names| &names.months[..])
+        .and_then(|i| Month::try_from(i as u8 + 1).ok())
+}
+
+/// Looks up a weekday by its name or abbreviation in English or any of `locales`, ignoring case
+/// and diacritics.
+///
+/// English names take precedence, then the locales in the given order.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::Weekday;
+/// use utcize::locale::{Locale, lookup_weekday};
+///
+/// assert_eq!(lookup_weekday("Mittwoch", &Locale::ALL), Some(Weekday::Wed));
+/// assert_eq!(lookup_weekday("sábado", &[Locale::Spanish]), Some(Weekday::Sat));
+/// ```
+pub fn lookup_weekday(name: &str, locales: &[Locale]) -> Option<Weekday> {
+    let folded = fold(name);
+    lookup(&folded, locales, |names| &names.weekdays[..])
+        .and_then(|i| Weekday::try_from(i as u8).ok())
+}
+
+/// Translates localized month and weekday names in `s` to English, so chrono's `%b`, `%B`, `%a`
+/// and `%A` can read them.
+///
+/// Matching ignores case and diacritics. Names and abbreviations are replaced by the English
+/// three-letter abbreviation, which all four specifiers read, dropping a dot after an
+/// abbreviation (`janv.`). When a month is translated, day ordinals (`1er`, `1.`, `1º`) and
+/// connecting words (`de`) are dropped as well, and whitespace is collapsed. English names are
+/// kept as they are.
+///
+/// # Returns
+/// * `Some(String)` with the translated input, if it contains a localized month or weekday.
+/// * `None` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::locale::{Locale, translate_names};
+///
+/// assert_eq!(translate_names("1. März 2023", &Locale::ALL).unwrap(), "1 Mar 2023");
+/// assert_eq!(translate_names("1er juin 2023", &Locale::ALL).unwrap(), "1 Jun 2023");
+/// assert_eq!(translate_names("1 de junio de 2023", &Locale::ALL).unwrap(), "1 Jun 2023");
+/// assert_eq!(translate_names("jeudi 1 juin", &Locale::ALL).unwrap(), "Thu 1 Jun");
+/// assert_eq!(translate_names("1 June 2023", &Locale::ALL), None);
+/// ```
+pub fn translate_names(s: &str, locales: &[Locale]) -> Option<String> {
+    let mut tokens = tokenize(s);
+    let mut translated_month = false;
+    let mut translated_any = false;
+
+    for i in 0..tokens.len() {
+        if !tokens[i].is_word {
+            continue;
+        }
+        let folded = fold(&tokens[i].text);
+        if lookup(&folded, &[], |names| &names.months[..]).is_some()
+            || lookup(&folded, &[], |names| &names.weekdays[..]).is_some()
+        {
+            continue;
+        }
+
+        let english = if let Some(month) = lookup(&folded, locales, |names| &names.months[..]) {
+            translated_month = true;
+            // The dot of a German day ordinal, as in `1. März`.
+            if let Some(prev) = i.checked_sub(1).map(|j| &mut tokens[j]) {
+                let trimmed = prev.text.trim_end();
+                if let Some(day) = trimmed.strip_suffix('.')
+                    && day.ends_with(|c: char| c.is_ascii_digit())
+                {
+                    prev.text = format!("{day} ");
+                }
+            }
+            english_abbreviation(&ENGLISH.months, month)
+        } else if let Some(weekday) = lookup(&folded, locales, |names| &names.weekdays[..]) {
+            english_abbreviation(&ENGLISH.weekdays, weekday)
+        } else {
+            continue;
+        };
+        translated_any = true;
+        tokens[i].text = english;
+        tokens[i].is_word = false;
+        // The dot of an abbreviation, as in `janv.`.
+        if let Some(next) = tokens.get_mut(i + 1)
+            && let Some(rest) = next.text.strip_prefix('.')
+        {
+            next.text = rest.to_string();
+        }
+        // The `-feira` of Portuguese weekdays, as in `segunda-feira`.
+        if tokens.get(i + 1).is_some_and(|next| next.text == "-")
+            && tokens
+                .get(i + 2)
+                .is_some_and(|word| fold(&word.text) == "feira")
+        {
+            tokens[i + 1].text.clear();
+            tokens[i + 2].text.clear();
+        }
+    }
+    if !translated_any {
+        return None;
+    }
+    if !translated_month {
+        return Some(tokens.into_iter().map(|token| token.text).collect());
+    }
+
+    let mut out = String::with_capacity(s.len());
+    for (i, token) in tokens.iter().enumerate() {
+        let folded = fold(&token.text);
+        let after_digit = i > 0 && tokens[i - 1].text.ends_with(|c: char| c.is_ascii_digit());
+        let skip = token.is_word
+            && (CONNECTORS.contains(&folded.as_str())
+                || (after_digit && ORDINAL_SUFFIXES.contains(&folded.as_str())));
+        if !skip {
+            out.push_str(&token.text);
+        }
+    }
+    Some(out.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// A run of letters, or of other characters, in the input.
+struct Token {
+    text: String,
+    is_word: bool,
+}
+
+/// Splits `s` into alternating runs of letters and other characters.
+fn tokenize(s: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    for c in s.chars() {
+        let is_word = c.is_alphabetic();
+        match tokens.last_mut() {
+            Some(last) if last.is_word == is_word => last.text.push(c),
+            _ => tokens.push(Token {
+                text: c.to_string(),
+                is_word,
+            }),
+        }
+    }
+    tokens
+}
+
+/// Returns the index of `folded` in the names selected by `select`, trying English first and
+/// then `locales` in order.
+fn lookup(
+    folded: &str,
+    locales: &[Locale],
+    select: impl Fn(&'static Names) -> &'static [&'static [&'static str]],
+) -> Option<usize> {
+    std::iter::once(&ENGLISH)
+        .chain(locales.iter().map(Locale::names))
+        .find_map(|names| {
+            select(names)
+                .iter()
+                .enumerate()
+                .find_map(|(i, variants)| variants.contains(&folded).then_some(i))
+        })
+}
+
+/// Returns the capitalized three-letter English abbreviation of the name at `index`.
+///
+/// chrono's `%b` and `%a` only read abbreviations, while `%B` and `%A` read both forms.
+fn english_abbreviation(names: &[&[&'static str]], index: usize) -> String {
+    let name = &names[index][0][..3];
+    name[..1].to_uppercase() + &name[1..]
+}
+
+/// Lowercases `s` and strips the diacritics used in the supported languages.
+fn fold(s: &str) -> String {
+    s.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            c => c,
+        })
+        .collect()
+}
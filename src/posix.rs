@@ -0,0 +1,357 @@
+use crate::error::TimeParseError;
+use crate::provider::{ZoneOffset, ZoneRules};
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeDelta};
+
+/// A timezone described by a POSIX TZ string, such as `CET-1CEST,M3.5.0,M10.5.0/3` or `WIB-7`.
+///
+/// Offsets in POSIX TZ strings are *west* of UTC, so `WIB-7` is UTC+07:00.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::posix::parse_posix_tz;
+///
+/// let berlin = parse_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// assert_eq!(berlin.std_abbreviation(), "CET");
+/// assert_eq!(berlin.std_offset().local_minus_utc(), 3600);
+/// assert_eq!(berlin.dst_offset().unwrap().local_minus_utc(), 7200);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosixTz {
+    source: String,
+    std_abbreviation: String,
+    std_offset: FixedOffset,
+    dst: Option<DaylightRule>,
+}
+
+/// The daylight saving time part of a POSIX TZ string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DaylightRule {
+    abbreviation: String,
+    offset: FixedOffset,
+    start: TransitionRule,
+    end: TransitionRule,
+}
+
+/// When daylight saving time starts or ends: a date and a local time in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TransitionRule {
+    date: RuleDate,
+    time: i32,
+}
+
+/// The date of a transition within a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: day 1 to 365, never counting February 29.
+    Julian(u32),
+
+    /// `n`: day 0 to 365, counting February 29.
+    Ordinal(u32),
+
+    /// `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` (5 is the last) of month `m`.
+    MonthWeekDay { month: u32, week: u32, weekday: u32 },
+}
+
+impl PosixTz {
+    /// Returns the TZ string as given.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the abbreviation of standard time, e.g. `CET`.
+    pub fn std_abbreviation(&self) -> &str {
+        &self.std_abbreviation
+    }
+
+    /// Returns the UTC offset of standard time.
+    pub fn std_offset(&self) -> FixedOffset {
+        self.std_offset
+    }
+
+    /// Returns the abbreviation of daylight saving time, e.g. `CEST`, if the zone observes it.
+    pub fn dst_abbreviation(&self) -> Option<&str> {
+        self.dst.as_ref().map(|dst| dst.abbreviation.as_str())
+    }
+
+    /// Returns the UTC offset of daylight saving time, if the zone observes it.
+    pub fn dst_offset(&self) -> Option<FixedOffset> {
+        self.dst.as_ref().map(|dst| dst.offset)
+    }
+}
+
+impl ZoneRules for PosixTz {
+    fn name(&self) -> &str {
+        &self.source
+    }
+
+    fn offset_at(&self, utc: &NaiveDateTime) -> ZoneOffset {
+        let standard = ZoneOffset {
+            utc_offset: self.std_offset,
+            is_dst: false,
+        };
+        let Some(dst) = &self.dst else {
+            return standard;
+        };
+
+        // Daylight saving time runs from `start` to the next `end`, which falls into the
+        // following year in the southern hemisphere.
+        let in_dst = (utc.year() - 1..=utc.year() + 1).any(|year| {
+            let start = dst.start.instant(year, self.std_offset);
+            let end = dst.end.instant(year, dst.offset);
+            let end = match (start, end) {
+                (Some(start), Some(end)) if end <= start => dst.end.instant(year + 1, dst.offset),
+                _ => end,
+            };
+            matches!((start, end), (Some(start), Some(end)) if start <= *utc && *utc < end)
+        });
+
+        if in_dst {
+            ZoneOffset {
+                utc_offset: dst.offset,
+                is_dst: true,
+            }
+        } else {
+            standard
+        }
+    }
+}
+
+impl TransitionRule {
+    /// Returns the UTC instant of the transition in `year`, given the offset in effect before it.
+    fn instant(&self, year: i32, offset: FixedOffset) -> Option<NaiveDateTime> {
+        let midnight = self.date.in_year(year)?.and_hms_opt(0, 0, 0)?;
+        let seconds = i64::from(self.time) - i64::from(offset.local_minus_utc());
+        midnight.checked_add_signed(TimeDelta::seconds(seconds))
+    }
+}
+
+impl RuleDate {
+    /// Returns the date in `year`.
+    fn in_year(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            RuleDate::Julian(day) => {
+                let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                NaiveDate::from_yo_opt(year, if leap && day >= 60 { day + 1 } else { day })
+            }
+            RuleDate::Ordinal(day) => NaiveDate::from_yo_opt(year, day + 1),
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                let first_weekday = (weekday + 7 - first.weekday().num_days_from_sunday()) % 7;
+                let mut day = first + TimeDelta::days(i64::from(first_weekday + (week - 1) * 7));
+                // Week 5 means the last such weekday, which may be in the fourth week.
+                while day.month() != month {
+                    day -= TimeDelta::days(7);
+                }
+                Some(day)
+            }
+        }
+    }
+}
+
+/// Parses a POSIX TZ string, such as `CET-1CEST,M3.5.0,M10.5.0/3`, `<+07>-7` or `WIB-7`.
+///
+/// # Supported formats
+///
+/// - `std offset`: a zone without daylight saving time, e.g. `WIB-7` or `<+0530>-5:30`.
+/// - `std offset dst [offset],start[/time],end[/time]`: a zone with daylight saving time.
+///   The daylight offset defaults to one hour ahead of standard time, and the transition
+///   time to `02:00`.
+/// - `std offset dst [offset]` without rules uses the US rules, `M3.2.0,M11.1.0`.
+///
+/// Dates are `Jn` (day 1 to 365, never counting February 29), `n` (day 0 to 365) or `Mm.w.d`
+/// (weekday `d` of week `w` of month `m`, with week 5 the last). As in TZif footers, transition
+/// times may be negative or exceed 24 hours.
+///
+/// # Errors
+///
+/// Returns [`TimeParseError::MalformedTimezone`] if the string is not a valid POSIX TZ string.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use utcize::posix::parse_posix_tz;
+/// use utcize::provider::ZoneRules;
+///
+/// let wib = parse_posix_tz("WIB-7").unwrap();
+/// assert_eq!(wib.std_offset().local_minus_utc(), 7 * 3600);
+/// assert!(wib.dst_offset().is_none());
+///
+/// let berlin = parse_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let summer = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// assert!(berlin.offset_at(&summer).is_dst);
+/// ```
+pub fn parse_posix_tz(s: &str) -> Result<PosixTz, TimeParseError> {
+    parse(s.trim()).ok_or_else(|| TimeParseError::MalformedTimezone {
+        name: s.trim().to_string(),
+    })
+}
+
+/// Parses a POSIX TZ string, or returns `None` if it is malformed.
+fn parse(s: &str) -> Option<PosixTz> {
+    let mut cursor = Cursor(s);
+    let std_abbreviation = cursor.abbreviation()?;
+    let std_offset = cursor.utc_offset()?;
+
+    let dst = if cursor.0.is_empty() {
+        None
+    } else {
+        let abbreviation = cursor.abbreviation()?;
+        let offset = if cursor.0.is_empty() || cursor.0.starts_with(',') {
+            FixedOffset::east_opt(std_offset.local_minus_utc() + 3600)?
+        } else {
+            cursor.utc_offset()?
+        };
+        let (start, end) = if cursor.0.is_empty() {
+            DEFAULT_RULES
+        } else {
+            cursor.expect(',')?;
+            let start = cursor.rule()?;
+            cursor.expect(',')?;
+            (start, cursor.rule()?)
+        };
+        Some(DaylightRule {
+            abbreviation,
+            offset,
+            start,
+            end,
+        })
+    };
+
+    cursor.0.is_empty().then(|| PosixTz {
+        source: s.to_string(),
+        std_abbreviation,
+        std_offset,
+        dst,
+    })
+}
+
+/// The US rules, `M3.2.0,M11.1.0`, used when a TZ string names daylight saving time
+/// without saying when it applies.
+const DEFAULT_RULES: (TransitionRule, TransitionRule) = (
+    TransitionRule {
+        date: RuleDate::MonthWeekDay {
+            month: 3,
+            week: 2,
+            weekday: 0,
+        },
+        time: 2 * 3600,
+    },
+    TransitionRule {
+        date: RuleDate::MonthWeekDay {
+            month: 11,
+            week: 1,
+            weekday: 0,
+        },
+        time: 2 * 3600,
+    },
+);
+
+/// The unparsed remainder of a POSIX TZ string.
+struct Cursor<'a>(&'a str);
+
+impl Cursor<'_> {
+    /// Consumes `c`, if it comes next.
+    fn expect(&mut self, c: char) -> Option<()> {
+        self.0 = self.0.strip_prefix(c)?;
+        Some(())
+    }
+
+    /// Consumes the longest prefix of characters matching `f`.
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &str {
+        let end = self.0.find(|c| !f(c)).unwrap_or(self.0.len());
+        let (taken, rest) = self.0.split_at(end);
+        self.0 = rest;
+        taken
+    }
+
+    /// Consumes an abbreviation: three or more letters, or `<...>` quoting letters, digits
+    /// and signs.
+    fn abbreviation(&mut self) -> Option<String> {
+        let abbreviation = if self.expect('<').is_some() {
+            let quoted = self.take_while(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-');
+            let quoted = quoted.to_string();
+            self.expect('>')?;
+            quoted
+        } else {
+            self.take_while(|c| c.is_ascii_alphabetic()).to_string()
+        };
+        (abbreviation.len() >= 3).then_some(abbreviation)
+    }
+
+    /// Consumes a POSIX offset, which counts hours *west* of UTC, and returns it as a UTC offset.
+    fn utc_offset(&mut self) -> Option<FixedOffset> {
+        let west = self.time(24)?;
+        FixedOffset::east_opt(-west)
+    }
+
+    /// Consumes a transition rule: a date and an optional `/time`.
+    fn rule(&mut self) -> Option<TransitionRule> {
+        let date = if self.expect('J').is_some() {
+            RuleDate::Julian(self.number().filter(|day| (1..=365).contains(day))?)
+        } else if self.expect('M').is_some() {
+            let month = self.number().filter(|m| (1..=12).contains(m))?;
+            self.expect('.')?;
+            let week = self.number().filter(|w| (1..=5).contains(w))?;
+            self.expect('.')?;
+            let weekday = self.number().filter(|d| *d <= 6)?;
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            }
+        } else {
+            RuleDate::Ordinal(self.number().filter(|day| *day <= 365)?)
+        };
+
+        let time = if self.expect('/').is_some() {
+            self.time(167)?
+        } else {
+            2 * 3600
+        };
+        Some(TransitionRule { date, time })
+    }
+
+    /// Consumes a signed `hh[:mm[:ss]]` time with at most `max_hours` hours, in seconds.
+    fn time(&mut self, max_hours: u32) -> Option<i32> {
+        let sign = if self.expect('-').is_some() {
+            -1
+        } else {
+            self.expect('+');
+            1
+        };
+        let hours = self.number().filter(|h| *h <= max_hours)?;
+        let mut minutes = 0;
+        let mut seconds = 0;
+        if self.expect(':').is_some() {
+            minutes = self.two_digits()?;
+            if self.expect(':').is_some() {
+                seconds = self.two_digits()?;
+            }
+        }
+        if minutes >= 60 || seconds >= 60 {
+            return None;
+        }
+        Some(sign * i32::try_from(hours * 3600 + minutes * 60 + seconds).ok()?)
+    }
+
+    /// Consumes an unsigned number of up to three digits.
+    fn number(&mut self) -> Option<u32> {
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() || digits.len() > 3 {
+            return None;
+        }
+        digits.parse().ok()
+    }
+
+    /// Consumes exactly two digits.
+    fn two_digits(&mut self) -> Option<u32> {
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        (digits.len() == 2).then(|| digits.parse().ok()).flatten()
+    }
+}
@@ -0,0 +1,720 @@
+use crate::error::TimeParseError;
+use crate::posix::{PosixTz, parse_posix_tz};
+use crate::tz::is_well_formed_identifier;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeDelta, TimeZone};
+use chrono_tz::{IANA_TZDB_VERSION, OffsetComponents, TZ_VARIANTS, Tz};
+use parse_zoneinfo::line::{Line, LineParser};
+use parse_zoneinfo::table::{Table, TableBuilder};
+use parse_zoneinfo::transitions::TableTransitions;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// The offset of a timezone at a given instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZoneOffset {
+    /// The total UTC offset, including any daylight saving time.
+    pub utc_offset: FixedOffset,
+
+    /// `true` if daylight saving time is in effect.
+    pub is_dst: bool,
+}
+
+/// The rules of a single timezone: which UTC offset is in effect at any instant.
+pub trait ZoneRules: fmt::Debug + Send + Sync {
+    /// Returns the name of the timezone, e.g. `Asia/Jakarta`.
+    fn name(&self) -> &str;
+
+    /// Returns the offset in effect at the given UTC datetime.
+    fn offset_at(&self, utc: &NaiveDateTime) -> ZoneOffset;
+
+    /// Maps a local datetime onto the timezone.
+    ///
+    /// Transitions are looked for within a day of `local`, which covers every transition
+    /// in the tz database.
+    fn local_offsets(&self, local: &NaiveDateTime) -> LocalOffsets {
+        let day = TimeDelta::days(1);
+        let before = self.offset_at(&(*local - day));
+        let after = self.offset_at(&(*local + day));
+
+        let mut valid: Vec<ZoneOffset> = Vec::new();
+        for candidate in [before, self.offset_at(local), after] {
+            if valid.iter().any(|v| v.utc_offset == candidate.utc_offset) {
+                continue;
+            }
+            let utc = *local - TimeDelta::seconds(candidate.utc_offset.local_minus_utc().into());
+            let actual = self.offset_at(&utc);
+            if actual.utc_offset == candidate.utc_offset {
+                valid.push(actual);
+            }
+        }
+        // A larger offset maps the same local time to an earlier instant.
+        valid.sort_by_key(|v| std::cmp::Reverse(v.utc_offset.local_minus_utc()));
+
+        match valid[..] {
+            [single] => LocalOffsets::Single(single),
+            [earliest, .., latest] => LocalOffsets::Ambiguous(earliest, latest),
+            [] => {
+                let transition = find_transition(self, *local - day, *local + day, before);
+                LocalOffsets::Gap {
+                    before,
+                    after: self.offset_at(&transition),
+                    transition,
+                }
+            }
+        }
+    }
+}
+
+/// Returns the first UTC second in `(from, to]` whose offset in `rules` differs from `before`.
+fn find_transition<R>(
+    rules: &R,
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+    before: ZoneOffset,
+) -> NaiveDateTime
+where
+    R: ZoneRules + ?Sized,
+{
+    let (mut lo, mut hi) = (from.and_utc().timestamp(), to.and_utc().timestamp());
+    let at = |t: i64| {
+        DateTime::from_timestamp(t, 0)
+            .unwrap_or_default()
+            .naive_utc()
+    };
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if rules.offset_at(&at(mid)).utc_offset == before.utc_offset {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    at(hi)
+}
+
+/// A source of timezone rules, such as the bundled chrono-tz data or the system's
+/// `/usr/share/zoneinfo`.
+pub trait TimeZoneProvider: fmt::Debug + Send + Sync {
+    /// Returns the timezone with the given name, if the provider knows it.
+    fn zone(&self, name: &str) -> Option<IanaZone>;
+
+    /// Returns the names of all timezones the provider knows.
+    fn zone_names(&self) -> Vec<String>;
+
+    /// Returns the version of the tz database the provider uses, e.g. `2025b`, if known.
+    fn version(&self) -> Option<String>;
+}
+
+/// How a local datetime maps onto the instants of a timezone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalOffsets {
+    /// The local time occurs exactly once.
+    Single(ZoneOffset),
+
+    /// The local time occurs twice, e.g. when clocks are set back; the earlier instant first.
+    Ambiguous(ZoneOffset, ZoneOffset),
+
+    /// The local time is skipped, e.g. when clocks are set forward.
+    Gap {
+        /// The offset in effect before the gap.
+        before: ZoneOffset,
+
+        /// The offset in effect after the gap.
+        after: ZoneOffset,
+
+        /// The UTC instant of the transition that causes the gap.
+        transition: NaiveDateTime,
+    },
+}
+
+/// An IANA timezone, backed by the rules of a [`TimeZoneProvider`].
+///
+/// Cheap to clone; the rules are shared.
+#[derive(Clone)]
+pub struct IanaZone(Arc<dyn ZoneRules>);
+
+impl IanaZone {
+    /// Wraps the given rules.
+    pub fn new<R>(rules: R) -> Self
+    where
+        R: ZoneRules + 'static,
+    {
+        Self(Arc::new(rules))
+    }
+
+    /// Returns the name of the timezone, e.g. `Asia/Jakarta`.
+    pub fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    /// Returns the offset in effect at the given UTC datetime.
+    pub fn offset_at(&self, utc: &NaiveDateTime) -> ZoneOffset {
+        self.0.offset_at(utc)
+    }
+
+    /// Returns the bundled chrono-tz timezone of the same name, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utcize::provider::IanaZone;
+    ///
+    /// let zone = IanaZone::from(chrono_tz::Asia::Jakarta);
+    /// assert_eq!(zone.as_tz(), Some(chrono_tz::Asia::Jakarta));
+    /// ```
+    pub fn as_tz(&self) -> Option<Tz> {
+        self.name().parse().ok()
+    }
+
+    /// Maps a local datetime onto the timezone.
+    pub fn local_offsets(&self, local: &NaiveDateTime) -> LocalOffsets {
+        self.0.local_offsets(local)
+    }
+}
+
+impl fmt::Debug for IanaZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IanaZone").field(&self.name()).finish()
+    }
+}
+
+impl ZoneRules for IanaZone {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn offset_at(&self, utc: &NaiveDateTime) -> ZoneOffset {
+        self.0.offset_at(utc)
+    }
+
+    fn local_offsets(&self, local: &NaiveDateTime) -> LocalOffsets {
+        self.0.local_offsets(local)
+    }
+}
+
+impl From<Tz> for IanaZone {
+    fn from(tz: Tz) -> Self {
+        Self::new(tz)
+    }
+}
+
+impl ZoneRules for Tz {
+    fn name(&self) -> &str {
+        Tz::name(*self)
+    }
+
+    fn offset_at(&self, utc: &NaiveDateTime) -> ZoneOffset {
+        let offset = self.offset_from_utc_datetime(utc);
+        ZoneOffset {
+            utc_offset: offset.fix(),
+            is_dst: !offset.dst_offset().is_zero(),
+        }
+    }
+}
+
+/// A timezone defined by a list of transitions, as read from TZif files or tzdata sources.
+#[derive(Debug, Clone)]
+pub struct TransitionZone {
+    name: String,
+    initial: ZoneOffset,
+    transitions: Vec<(i64, ZoneOffset)>,
+    footer: Option<PosixTz>,
+}
+
+impl TransitionZone {
+    /// Creates a timezone from the offset in effect before the first transition and a list of
+    /// transitions, each given as a Unix timestamp and the offset in effect from then on.
+    pub fn new<S>(name: S, initial: ZoneOffset, mut transitions: Vec<(i64, ZoneOffset)>) -> Self
+    where
+        S: Into<String>,
+    {
+        transitions.sort_by_key(|(at, _)| *at);
+        Self {
+            name: name.into(),
+            initial,
+            transitions,
+            footer: None,
+        }
+    }
+
+    /// Reads a timezone from the contents of a TZif file (RFC 8536), versions 1 to 4.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError::TimezoneData`] if the data is not valid TZif.
+    pub fn from_tzif<S>(name: S, data: &[u8]) -> Result<Self, TimeParseError>
+    where
+        S: Into<String>,
+    {
+        let name = name.into();
+        let invalid = |reason: &str| TimeParseError::TimezoneData {
+            origin: name.clone(),
+            reason: reason.to_string(),
+        };
+
+        let (initial, transitions, footer) =
+            parse_tzif(data).ok_or_else(|| invalid("not a valid TZif file"))?;
+        Ok(Self {
+            name,
+            initial,
+            transitions,
+            footer: footer.and_then(|footer| parse_posix_tz(&footer).ok()),
+        })
+    }
+
+    /// Returns the POSIX TZ string of a TZif v2+ footer, describing the rules after the
+    /// last transition.
+    pub fn footer(&self) -> Option<&str> {
+        self.footer.as_ref().map(PosixTz::as_str)
+    }
+}
+
+impl ZoneRules for TransitionZone {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn offset_at(&self, utc: &NaiveDateTime) -> ZoneOffset {
+        let t = utc.and_utc().timestamp();
+        let i = self.transitions.partition_point(|(at, _)| *at <= t);
+        // The footer covers every instant after the last transition, or all of them if
+        // there are no transitions.
+        if i == self.transitions.len()
+            && let Some(footer) = &self.footer
+        {
+            return footer.offset_at(utc);
+        }
+        match i {
+            0 => self.initial,
+            i => self.transitions[i - 1].1,
+        }
+    }
+}
+
+/// A parsed TZif file: the initial offset, the transitions and the footer.
+type Tzif = (ZoneOffset, Vec<(i64, ZoneOffset)>, Option<String>);
+
+/// Parses the contents of a TZif file, preferring the 64-bit data of version 2 and later.
+fn parse_tzif(data: &[u8]) -> Option<Tzif> {
+    struct Header {
+        version: u8,
+        isutcnt: usize,
+        isstdcnt: usize,
+        leapcnt: usize,
+        timecnt: usize,
+        typecnt: usize,
+        charcnt: usize,
+    }
+
+    fn header(data: &[u8]) -> Option<Header> {
+        if data.get(..4)? != b"TZif" {
+            return None;
+        }
+        let count = |i: usize| -> Option<usize> {
+            let bytes = data.get(20 + i * 4..24 + i * 4)?;
+            Some(u32::from_be_bytes(bytes.try_into().ok()?) as usize)
+        };
+        Some(Header {
+            version: *data.get(4)?,
+            isutcnt: count(0)?,
+            isstdcnt: count(1)?,
+            leapcnt: count(2)?,
+            timecnt: count(3)?,
+            typecnt: count(4)?,
+            charcnt: count(5)?,
+        })
+    }
+
+    fn block_len(h: &Header, time_size: usize) -> usize {
+        h.timecnt * time_size
+            + h.timecnt
+            + h.typecnt * 6
+            + h.charcnt
+            + h.leapcnt * (time_size + 4)
+            + h.isstdcnt
+            + h.isutcnt
+    }
+
+    let v1 = header(data)?;
+    let (h, body, time_size) = if v1.version >= b'2' {
+        let rest = data.get(44 + block_len(&v1, 4)..)?;
+        (header(rest)?, rest.get(44..)?, 8)
+    } else {
+        (v1, data.get(44..)?, 4)
+    };
+
+    let times = body.get(..h.timecnt * time_size)?;
+    let indices = body.get(h.timecnt * time_size..h.timecnt * (time_size + 1))?;
+    let types_start = h.timecnt * (time_size + 1);
+    let types = body.get(types_start..types_start + h.typecnt * 6)?;
+
+    let types: Vec<ZoneOffset> = types
+        .chunks_exact(6)
+        .map(|t| {
+            let utoff = i32::from_be_bytes(t[..4].try_into().ok()?);
+            Some(ZoneOffset {
+                utc_offset: FixedOffset::east_opt(utoff)?,
+                is_dst: t[4] != 0,
+            })
+        })
+        .collect::<Option<_>>()?;
+    let transitions = times
+        .chunks_exact(time_size)
+        .zip(indices)
+        .map(|(time, &index)| {
+            let at = match time_size {
+                8 => i64::from_be_bytes(time.try_into().ok()?),
+                _ => i64::from(i32::from_be_bytes(time.try_into().ok()?)),
+            };
+            Some((at, *types.get(usize::from(index))?))
+        })
+        .collect::<Option<_>>()?;
+
+    let footer = if h.version >= b'2' {
+        body.get(block_len(&h, time_size)..)
+            .and_then(|rest| rest.strip_prefix(b"\n"))
+            .and_then(|rest| rest.split(|&b| b == b'\n').next())
+            .and_then(|footer| std::str::from_utf8(footer).ok())
+            .filter(|footer| !footer.is_empty())
+            .map(str::to_string)
+    } else {
+        None
+    };
+
+    Some((*types.first()?, transitions, footer))
+}
+
+/// The tz database compiled into the binary by chrono-tz.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::provider::{BundledProvider, TimeZoneProvider};
+///
+/// let provider = BundledProvider;
+/// assert_eq!(provider.zone("Asia/Jakarta").unwrap().name(), "Asia/Jakarta");
+/// assert!(provider.version().is_some());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BundledProvider;
+
+impl TimeZoneProvider for BundledProvider {
+    fn zone(&self, name: &str) -> Option<IanaZone> {
+        name.parse::<Tz>().ok().map(IanaZone::from)
+    }
+
+    fn zone_names(&self) -> Vec<String> {
+        TZ_VARIANTS.iter().map(|tz| tz.name().to_string()).collect()
+    }
+
+    fn version(&self) -> Option<String> {
+        Some(IANA_TZDB_VERSION.to_string())
+    }
+}
+
+/// A directory of compiled TZif files, such as the system's `/usr/share/zoneinfo`.
+///
+/// Files are read when a zone is first requested and cached afterwards, so tzdata updates
+/// installed by the operating system are picked up without rebuilding.
+///
+/// # Examples
+///
+/// ```no_run
+/// use utcize::provider::{TimeZoneProvider, TzifProvider};
+///
+/// let provider = TzifProvider::system().unwrap();
+/// let jakarta = provider.zone("Asia/Jakarta").unwrap();
+/// println!("tzdata {:?}", provider.version());
+/// ```
+#[derive(Debug)]
+pub struct TzifProvider {
+    root: PathBuf,
+    version: Option<String>,
+    cache: Mutex<HashMap<String, IanaZone>>,
+    names: OnceLock<Vec<String>>,
+}
+
+impl TzifProvider {
+    /// Uses the TZif files under `root`.
+    ///
+    /// The tzdb version is read from `tzdata.zi` or `+VERSION` in `root`, if present.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError::TimezoneData`] if `root` is not a directory.
+    pub fn new<P>(root: P) -> Result<Self, TimeParseError>
+    where
+        P: Into<PathBuf>,
+    {
+        let root = root.into();
+        if !root.is_dir() {
+            return Err(TimeParseError::TimezoneData {
+                origin: root.display().to_string(),
+                reason: "not a directory".to_string(),
+            });
+        }
+
+        let version = fs::read_to_string(root.join("tzdata.zi"))
+            .ok()
+            .and_then(|zi| {
+                zi.lines()
+                    .find_map(|line| line.strip_prefix("# version "))
+                    .map(|v| v.trim().to_string())
+            })
+            .or_else(|| {
+                fs::read_to_string(root.join("+VERSION"))
+                    .ok()
+                    .map(|v| v.trim().to_string())
+            });
+
+        Ok(Self {
+            root,
+            version,
+            cache: Mutex::new(HashMap::new()),
+            names: OnceLock::new(),
+        })
+    }
+
+    /// Uses the system's TZif files: the directory named by `TZDIR`, or `/usr/share/zoneinfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError::TimezoneData`] if the directory does not exist.
+    pub fn system() -> Result<Self, TimeParseError> {
+        let root = std::env::var_os("TZDIR").unwrap_or_else(|| "/usr/share/zoneinfo".into());
+        Self::new(root)
+    }
+
+    /// Returns the directory the TZif files are read from.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl TimeZoneProvider for TzifProvider {
+    fn zone(&self, name: &str) -> Option<IanaZone> {
+        if !is_well_formed_identifier(name) {
+            return None;
+        }
+
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(zone) = cache.get(name) {
+            return Some(zone.clone());
+        }
+        let data = fs::read(self.root.join(name)).ok()?;
+        let zone = IanaZone::new(TransitionZone::from_tzif(name, &data).ok()?);
+        cache.insert(name.to_string(), zone.clone());
+        Some(zone)
+    }
+
+    fn zone_names(&self) -> Vec<String> {
+        fn walk(dir: &Path, prefix: &str, names: &mut Vec<String>) {
+            let Ok(entries) = fs::read_dir(dir) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                let file_name = entry.file_name().to_string_lossy().into_owned();
+                let name = format!("{prefix}{file_name}");
+                let path = entry.path();
+                if path.is_dir() {
+                    // `posix/` and `right/` duplicate the whole database.
+                    if prefix.is_empty() && (file_name == "posix" || file_name == "right") {
+                        continue;
+                    }
+                    walk(&path, &format!("{name}/"), names);
+                } else if is_well_formed_identifier(&name)
+                    && fs::read(&path).is_ok_and(|data| data.starts_with(b"TZif"))
+                {
+                    names.push(name);
+                }
+            }
+        }
+
+        self.names
+            .get_or_init(|| {
+                let mut names = Vec::new();
+                walk(&self.root, "", &mut names);
+                names.retain(|name| name != "localtime" && name != "posixrules");
+                names.sort();
+                names
+            })
+            .clone()
+    }
+
+    fn version(&self) -> Option<String> {
+        self.version.clone()
+    }
+}
+
+/// The standard tzdata source files holding zones, rules and links.
+const TZDATA_SOURCE_FILES: &[&str] = &[
+    "africa",
+    "antarctica",
+    "asia",
+    "australasia",
+    "europe",
+    "northamerica",
+    "southamerica",
+    "etcetera",
+    "backward",
+];
+
+/// Raw tzdata source files, as published by IANA (`africa`, `europe`, ...).
+///
+/// Zones are compiled from the rules when first requested, for the years 1800 to 2100.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::provider::{TimeZoneProvider, TzdataSourceProvider};
+///
+/// let source = "
+/// Rule  Test  2000  max  -  Mar  lastSun  2:00  1:00  S
+/// Rule  Test  2000  max  -  Oct  lastSun  3:00  0     -
+/// Zone  Test/Zone  1:00  Test  T%sT
+/// ";
+/// let provider = TzdataSourceProvider::from_sources([source], Some("2025b".into())).unwrap();
+/// assert!(provider.zone("Test/Zone").is_some());
+/// assert_eq!(provider.version().as_deref(), Some("2025b"));
+/// ```
+#[derive(Debug)]
+pub struct TzdataSourceProvider {
+    table: Table,
+    version: Option<String>,
+    cache: Mutex<HashMap<String, IanaZone>>,
+}
+
+impl TzdataSourceProvider {
+    /// Reads the standard tzdata source files present in `dir`.
+    ///
+    /// The tzdb version is read from the `version` file, or the first release in `NEWS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError::TimezoneData`] if no source file can be read or a line
+    /// cannot be parsed.
+    pub fn from_dir<P>(dir: P) -> Result<Self, TimeParseError>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+        let sources: Vec<String> = TZDATA_SOURCE_FILES
+            .iter()
+            .filter_map(|file| fs::read_to_string(dir.join(file)).ok())
+            .collect();
+        if sources.is_empty() {
+            return Err(TimeParseError::TimezoneData {
+                origin: dir.display().to_string(),
+                reason: "no tzdata source files found".to_string(),
+            });
+        }
+
+        let version = fs::read_to_string(dir.join("version"))
+            .ok()
+            .map(|v| v.trim().to_string())
+            .or_else(|| {
+                fs::read_to_string(dir.join("NEWS")).ok().and_then(|news| {
+                    news.lines().find_map(|line| {
+                        let release = line.strip_prefix("Release ")?;
+                        release.split_whitespace().next().map(str::to_string)
+                    })
+                })
+            });
+
+        Self::from_sources(sources, version)
+    }
+
+    /// Reads tzdata from the contents of source files, with the given tzdb version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError::TimezoneData`] if a line cannot be parsed.
+    pub fn from_sources<I, S>(sources: I, version: Option<String>) -> Result<Self, TimeParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let parser = LineParser::default();
+        let mut builder = TableBuilder::new();
+        for source in sources {
+            for line in source.as_ref().lines() {
+                let line = line.split('#').next().unwrap_or_default();
+                let invalid = |reason: String| TimeParseError::TimezoneData {
+                    origin: line.trim().to_string(),
+                    reason,
+                };
+                let parsed = parser.parse_str(line).map_err(|e| invalid(e.to_string()))?;
+                let added = match parsed {
+                    Line::Space => Ok(()),
+                    Line::Zone(zone) => builder.add_zone_line(zone),
+                    Line::Continuation(cont) => builder.add_continuation_line(cont),
+                    Line::Rule(rule) => builder.add_rule_line(rule),
+                    Line::Link(link) => builder.add_link_line(link),
+                };
+                added.map_err(|e| invalid(e.to_string()))?;
+            }
+        }
+
+        Ok(Self {
+            table: builder.build(),
+            version,
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Compiles the transitions of a zone, following links.
+    fn compile(&self, name: &str) -> Option<TransitionZone> {
+        // Links to zones missing from the sources would otherwise panic.
+        let target = self.table.links.get(name).map_or(name, String::as_str);
+        self.table.zonesets.get(target)?;
+
+        let spans = self.table.timespans(name)?;
+        let offset = |span: &parse_zoneinfo::transitions::FixedTimespan| {
+            Some(ZoneOffset {
+                utc_offset: FixedOffset::east_opt(span.total_offset().try_into().ok()?)?,
+                is_dst: span.dst_offset != 0,
+            })
+        };
+        let transitions = spans
+            .rest
+            .iter()
+            .map(|(at, span)| Some((*at, offset(span)?)))
+            .collect::<Option<_>>()?;
+        Some(TransitionZone::new(
+            name,
+            offset(&spans.first)?,
+            transitions,
+        ))
+    }
+}
+
+impl TimeZoneProvider for TzdataSourceProvider {
+    fn zone(&self, name: &str) -> Option<IanaZone> {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(zone) = cache.get(name) {
+            return Some(zone.clone());
+        }
+        let zone = IanaZone::new(self.compile(name)?);
+        cache.insert(name.to_string(), zone.clone());
+        Some(zone)
+    }
+
+    fn zone_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .table
+            .zonesets
+            .keys()
+            .chain(self.table.links.keys())
+            .cloned()
+            .collect();
+        names.sort();
+        names
+    }
+
+    fn version(&self) -> Option<String> {
+        self.version.clone()
+    }
+}
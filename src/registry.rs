@@ -0,0 +1,356 @@
+use crate::error::TimeParseError;
+use crate::formats::{CompiledFormat, web_server_log_formats};
+use crate::types::{DateOrder, ParseOutcome, TimeZoneParsed};
+use crate::utcizer::Utcizer;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+
+/// A named bundle of parsing settings: custom formats, date order and fallback timezone.
+///
+/// A profile can extend other profiles by name: its own formats are tried first, then those of
+/// the profiles it extends, in order. Its date order and fallback timezone, if unset, are taken
+/// from the first extended profile that sets them.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::registry::FormatProfile;
+/// use utcize::tz::parse_timezone_str;
+/// use utcize::types::DateOrder;
+///
+/// let profile = FormatProfile::new("payments-legacy")
+///     .formats(["%d/%m/%Y %H.%M"])
+///     .date_order(DateOrder::DayFirst)
+///     .fallback_tz(parse_timezone_str("Asia/Jakarta").unwrap())
+///     .extends(["base"]);
+/// assert_eq!(profile.name(), "payments-legacy");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FormatProfile {
+    name: String,
+    formats: Vec<String>,
+    date_order: Option<DateOrder>,
+    fallback_tz: Option<TimeZoneParsed>,
+    extends: Vec<String>,
+}
+
+impl FormatProfile {
+    /// Creates an empty profile with the given name.
+    pub fn new<S>(name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            formats: Vec::new(),
+            date_order: None,
+            fallback_tz: None,
+            extends: Vec::new(),
+        }
+    }
+
+    /// Returns the built-in `web-server-log` profile, for Apache and Nginx log timestamps such
+    /// as `[10/Oct/2000:13:55:36 -0700]` or `[Thu Jun 01 10:00:00.123456 2023]`.
+    ///
+    /// See [`web_server_log_formats`] for the formats. Timestamps without an offset, as in
+    /// error logs, are read in UTC; extend the profile to set another fallback timezone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utcize::registry::{FormatProfile, FormatRegistry};
+    /// use utcize::tz::parse_timezone_str;
+    ///
+    /// let registry = FormatRegistry::with_builtin_profiles();
+    /// registry
+    ///     .register(
+    ///         FormatProfile::new("nginx")
+    ///             .fallback_tz(parse_timezone_str("Europe/Berlin").unwrap())
+    ///             .extends(["web-server-log"]),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let dt = registry.parse("nginx", "[10/Oct/2000:13:55:36 -0700]").unwrap();
+    /// assert_eq!(dt.to_rfc3339(), "2000-10-10T20:55:36+00:00");
+    /// ```
+    pub fn web_server_log() -> Self {
+        Self::new("web-server-log").formats(web_server_log_formats())
+    }
+
+    /// Sets the custom formats of the profile, tried before the default formats.
+    pub fn formats<I, S>(mut self, formats: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.formats = formats
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Sets the preferred order of day and month for numeric dates.
+    pub fn date_order(mut self, order: DateOrder) -> Self {
+        self.date_order = Some(order);
+        self
+    }
+
+    /// Sets the timezone used when the input has no timezone information.
+    pub fn fallback_tz(mut self, tz: TimeZoneParsed) -> Self {
+        self.fallback_tz = Some(tz);
+        self
+    }
+
+    /// Sets the profiles this profile extends, by name, most important first.
+    pub fn extends<I, S>(mut self, profiles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.extends = profiles
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Returns the name of the profile.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the profile's own custom formats, without those of extended profiles.
+    pub fn custom_formats(&self) -> &[String] {
+        &self.formats
+    }
+
+    /// Returns the profile's own date order, if set.
+    pub fn preferred_date_order(&self) -> Option<DateOrder> {
+        self.date_order
+    }
+
+    /// Returns the profile's own fallback timezone, if set.
+    pub fn preferred_fallback_tz(&self) -> Option<&TimeZoneParsed> {
+        self.fallback_tz.as_ref()
+    }
+
+    /// Returns the names of the profiles this profile extends.
+    pub fn extended_profiles(&self) -> &[String] {
+        &self.extends
+    }
+}
+
+/// A registry of named [`FormatProfile`]s, selected by name when parsing.
+///
+/// Profiles can be registered, replaced and removed at runtime. The registry is `Send + Sync`,
+/// so it can be shared behind an [`Arc`] while a configuration service updates it. The
+/// [`Utcizer`] of each profile is built on first use and reused until the registry changes.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::registry::{FormatProfile, FormatRegistry};
+/// use utcize::tz::parse_timezone_str;
+///
+/// let registry = FormatRegistry::new();
+/// registry
+///     .register(FormatProfile::new("base").fallback_tz(parse_timezone_str("Asia/Jakarta").unwrap()))
+///     .unwrap();
+/// registry
+///     .register(FormatProfile::new("pipes").formats(["%Y|%m|%d %H:%M"]).extends(["base"]))
+///     .unwrap();
+///
+/// let dt = registry.parse("pipes", "2023|06|01 10:00").unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+/// assert_eq!(registry.names(), ["base", "pipes"]);
+/// ```
+#[derive(Debug, Default)]
+pub struct FormatRegistry {
+    profiles: RwLock<BTreeMap<String, FormatProfile>>,
+    utcizers: RwLock<HashMap<String, Arc<Utcizer>>>,
+}
+
+impl FormatRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry holding the built-in profiles:
+    ///
+    /// - `web-server-log`: see [`FormatProfile::web_server_log`].
+    pub fn with_builtin_profiles() -> Self {
+        let registry = Self::new();
+        registry
+            .register(FormatProfile::web_server_log())
+            .expect("built-in profiles have valid formats");
+        registry
+    }
+
+    /// Registers a profile, replacing any profile of the same name.
+    ///
+    /// Profiles may extend profiles that are registered later; they are looked up when the
+    /// profile is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError::InvalidFormat`] if one of the profile's formats contains a
+    /// specifier chrono does not understand.
+    pub fn register(&self, profile: FormatProfile) -> Result<(), TimeParseError> {
+        for format in &profile.formats {
+            CompiledFormat::new(format)?;
+        }
+
+        let mut profiles = self.profiles.write().unwrap_or_else(|e| e.into_inner());
+        profiles.insert(profile.name.clone(), profile);
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Removes a profile and returns it, if it was registered.
+    pub fn remove(&self, name: &str) -> Option<FormatProfile> {
+        let mut profiles = self.profiles.write().unwrap_or_else(|e| e.into_inner());
+        let removed = profiles.remove(name);
+        self.invalidate();
+        removed
+    }
+
+    /// Returns the profile registered under `name`, as registered.
+    pub fn profile(&self, name: &str) -> Option<FormatProfile> {
+        let profiles = self.profiles.read().unwrap_or_else(|e| e.into_inner());
+        profiles.get(name).cloned()
+    }
+
+    /// Returns the names of all registered profiles, sorted.
+    pub fn names(&self) -> Vec<String> {
+        let profiles = self.profiles.read().unwrap_or_else(|e| e.into_inner());
+        profiles.keys().cloned().collect()
+    }
+
+    /// Returns all registered profiles, sorted by name.
+    pub fn profiles(&self) -> Vec<FormatProfile> {
+        let profiles = self.profiles.read().unwrap_or_else(|e| e.into_inner());
+        profiles.values().cloned().collect()
+    }
+
+    /// Returns the profile registered under `name` with the profiles it extends merged in:
+    /// all formats in the order they are tried, and the effective date order and fallback
+    /// timezone. The merged profile extends nothing.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeParseError::UnknownProfile`] if the profile, or a profile it extends, is not
+    ///   registered,
+    /// - [`TimeParseError::ProfileCycle`] if the profile extends itself, directly or not.
+    pub fn resolve(&self, name: &str) -> Result<FormatProfile, TimeParseError> {
+        let profiles = self.profiles.read().unwrap_or_else(|e| e.into_inner());
+        resolve(&profiles, name)
+    }
+
+    /// Returns the [`Utcizer`] for the profile registered under `name`.
+    ///
+    /// Inputs without a timezone are resolved in UTC, and numeric dates read month-first,
+    /// unless the profile or a profile it extends says otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`resolve`](Self::resolve).
+    pub fn utcizer(&self, name: &str) -> Result<Arc<Utcizer>, TimeParseError> {
+        // Held until the utcizer is cached, so a concurrent change cannot leave a stale one.
+        let profiles = self.profiles.read().unwrap_or_else(|e| e.into_inner());
+        {
+            let utcizers = self.utcizers.read().unwrap_or_else(|e| e.into_inner());
+            if let Some(utcizer) = utcizers.get(name) {
+                return Ok(Arc::clone(utcizer));
+            }
+        }
+
+        let profile = resolve(&profiles, name)?;
+        let mut builder = Utcizer::builder()
+            .custom_formats(&profile.formats)
+            .date_order(profile.date_order.unwrap_or_default());
+        if let Some(tz) = profile.fallback_tz {
+            builder = builder.fallback_tz(tz);
+        }
+        let utcizer = Arc::new(builder.build());
+
+        let mut utcizers = self.utcizers.write().unwrap_or_else(|e| e.into_inner());
+        utcizers.insert(name.to_string(), Arc::clone(&utcizer));
+        Ok(utcizer)
+    }
+
+    /// Parses a datetime string with the profile registered under `profile`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`resolve`](Self::resolve) and [`Utcizer::parse`].
+    pub fn parse(&self, profile: &str, s: &str) -> Result<DateTime<Utc>, TimeParseError> {
+        self.utcizer(profile)?.parse(s)
+    }
+
+    /// Parses a datetime string with the profile registered under `profile`, and reports how
+    /// it was interpreted.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`resolve`](Self::resolve) and [`Utcizer::parse_detailed`].
+    pub fn parse_detailed(&self, profile: &str, s: &str) -> Result<ParseOutcome, TimeParseError> {
+        self.utcizer(profile)?.parse_detailed(s)
+    }
+
+    /// Drops the cached utcizers, since any of them may depend on a changed profile.
+    fn invalidate(&self) {
+        let mut utcizers = self.utcizers.write().unwrap_or_else(|e| e.into_inner());
+        utcizers.clear();
+    }
+}
+
+/// Returns the profile `name` with the profiles it extends merged in.
+fn resolve(
+    profiles: &BTreeMap<String, FormatProfile>,
+    name: &str,
+) -> Result<FormatProfile, TimeParseError> {
+    let mut merged = FormatProfile::new(name);
+    merge(profiles, name, &mut Vec::new(), &mut merged)?;
+    Ok(merged)
+}
+
+/// Merges the profile `name` and the profiles it extends into `merged`, depth first.
+///
+/// `chain` holds the profiles being merged, to detect cycles.
+fn merge(
+    profiles: &BTreeMap<String, FormatProfile>,
+    name: &str,
+    chain: &mut Vec<String>,
+    merged: &mut FormatProfile,
+) -> Result<(), TimeParseError> {
+    if chain.iter().any(|n| n == name) {
+        chain.push(name.to_string());
+        return Err(TimeParseError::ProfileCycle {
+            chain: chain.clone(),
+        });
+    }
+    let profile = profiles
+        .get(name)
+        .ok_or_else(|| TimeParseError::UnknownProfile {
+            name: name.to_string(),
+        })?;
+
+    chain.push(name.to_string());
+    for format in &profile.formats {
+        if !merged.formats.contains(format) {
+            merged.formats.push(format.clone());
+        }
+    }
+    merged.date_order = merged.date_order.or(profile.date_order);
+    if merged.fallback_tz.is_none() {
+        merged.fallback_tz = profile.fallback_tz.clone();
+    }
+    for parent in &profile.extends {
+        merge(profiles, parent, chain, merged)?;
+    }
+    chain.pop();
+    Ok(())
+}
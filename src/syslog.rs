@@ -0,0 +1,126 @@
+use crate::error::TimeParseError;
+use crate::formats::{ClosestMatch, unmatched_by};
+use crate::types::{ParsedDatetime, ParsedInput, SyslogFormat, SyslogYear};
+use chrono::format::{Fixed, ParseErrorKind, Parsed, StrftimeItems, parse};
+use chrono::{DateTime, Datelike, NaiveDateTime, TimeDelta, Utc};
+
+/// The format of RFC 3164 timestamps. Whitespace matches any amount of whitespace, so both the
+/// space-padded `Jun  1` and the zero-padded `Jun 01` are accepted.
+const RFC3164_FORMAT: &str = "%b %d %H:%M:%S%.f";
+
+/// How far an RFC 3164 timestamp may lie after the reference instant and still be read in the
+/// reference year, to allow for the timezone of the sender and clock skew.
+const MAX_SYSLOG_SKEW: TimeDelta = TimeDelta::days(7);
+
+/// Attempts to parse a syslog timestamp: RFC 5424 (`2023-06-01T10:00:00.123456+07:00`) or
+/// RFC 3164 (`Jun  1 10:00:00`).
+///
+/// RFC 3164 timestamps have neither a year nor a timezone. The year is chosen by `year`, and the
+/// result is naive local time, to be resolved in the timezone of the sender.
+///
+/// # Returns
+/// * `None` if `s` has the shape of neither format.
+/// * `Some(Ok(ParsedDatetime::WithTimezone(_)))` for an RFC 5424 timestamp.
+/// * `Some(Ok(ParsedDatetime::Naive(_)))` for an RFC 3164 timestamp.
+/// * `Some(Err(TimeParseError::NilTimestamp))` for the RFC 5424 NILVALUE `-`.
+/// * `Some(Err(_))` if a field holds an impossible value, such as `Feb 30`.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::error::TimeParseError;
+/// use utcize::syslog::parse_syslog_timestamp;
+/// use utcize::types::{ParsedDatetime, SyslogYear};
+///
+/// let parsed = parse_syslog_timestamp("Jun  1 10:00:00", SyslogYear::Fixed(2023));
+/// match parsed.unwrap().unwrap() {
+///     ParsedDatetime::Naive(dt) => assert_eq!(dt.to_string(), "2023-06-01 10:00:00"),
+///     other => panic!("Expected Naive, got {other:?}"),
+/// }
+///
+/// let nil = parse_syslog_timestamp("-", SyslogYear::Current).unwrap();
+/// assert_eq!(nil.unwrap_err(), TimeParseError::NilTimestamp);
+/// ```
+pub fn parse_syslog_timestamp(
+    s: &str,
+    year: SyslogYear,
+) -> Option<Result<ParsedDatetime, TimeParseError>> {
+    parse_syslog_tracking(s, year).map(|result| result.map(|(parsed, _)| parsed.into()))
+}
+
+/// Like [`parse_syslog_timestamp`], also returning which format matched.
+pub(crate) fn parse_syslog_tracking(
+    s: &str,
+    year: SyslogYear,
+) -> Option<Result<(ParsedInput, SyslogFormat), TimeParseError>> {
+    if s == "-" {
+        return Some(Err(TimeParseError::NilTimestamp));
+    }
+
+    if is_rfc5424_shaped(s) {
+        return Some(match DateTime::parse_from_rfc3339(s) {
+            Ok(dt) => Ok((ParsedInput::WithOffset(dt), SyslogFormat::Rfc5424)),
+            Err(e) => {
+                let mut closest = ClosestMatch::default();
+                closest.record("RFC 5424", e.kind(), unmatched_by(s, Fixed::RFC3339));
+                Err(closest.into_error(s))
+            }
+        });
+    }
+
+    let mut parsed = Parsed::new();
+    let kind = match parse(&mut parsed, s, StrftimeItems::new(RFC3164_FORMAT)) {
+        Ok(()) => match parse_rfc3164_in_year(&parsed, year) {
+            Some(naive) => return Some(Ok((ParsedInput::Naive(naive), SyslogFormat::Rfc3164))),
+            None => ParseErrorKind::Impossible,
+        },
+        Err(e) if matches!(e.kind(), ParseErrorKind::OutOfRange) => e.kind(),
+        Err(_) => return None,
+    };
+    Some(Err(TimeParseError::InvalidFieldValue {
+        input: s.to_string(),
+        format: RFC3164_FORMAT.to_string(),
+        kind,
+    }))
+}
+
+/// Returns `true` if `s` starts with an RFC 5424 `FULL-DATE "T"`, such as `2023-06-01T`.
+fn is_rfc5424_shaped(s: &str) -> bool {
+    let b = s.as_bytes();
+    b.len() > 11
+        && b[..10].iter().enumerate().all(|(i, c)| {
+            if i == 4 || i == 7 {
+                *c == b'-'
+            } else {
+                c.is_ascii_digit()
+            }
+        })
+        && b[10] == b'T'
+}
+
+/// Completes a parsed RFC 3164 timestamp with the year chosen by `year`.
+///
+/// For [`SyslogYear::Current`] and [`SyslogYear::Relative`], the latest year that puts the
+/// timestamp no more than [`MAX_SYSLOG_SKEW`] after the reference wins, so a `Dec 31` entry read
+/// on January 1st lands in the previous year, and `Feb 29` in the last leap year. Returns `None`
+/// if the date exists in none of the candidate years.
+fn parse_rfc3164_in_year(parsed: &Parsed, year: SyslogYear) -> Option<NaiveDateTime> {
+    let in_year = |y: i32| {
+        let mut parsed = parsed.clone();
+        parsed.set_year(i64::from(y)).ok()?;
+        parsed.to_naive_datetime_with_offset(0).ok()
+    };
+
+    let reference = match year {
+        SyslogYear::Fixed(y) => return in_year(y),
+        SyslogYear::Current => Utc::now(),
+        SyslogYear::Relative(reference) => reference,
+    }
+    .naive_utc();
+
+    let latest = reference + MAX_SYSLOG_SKEW;
+    (latest.year() - 4..=latest.year())
+        .rev()
+        .filter_map(in_year)
+        .find(|dt| *dt <= latest)
+}
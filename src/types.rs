@@ -1,9 +1,10 @@
 use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
-use chrono_tz::Tz;
+use crate::posix::PosixTz;
+use crate::provider::IanaZone;
 /// Represents the unit precision of a Unix timestamp.
 ///
 /// Used to detect the scale of numeric epoch values when parsing.
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
 pub enum EpochKind {
     /// Timestamp in seconds since the Unix epoch.
     Seconds,
@@ -15,16 +16,154 @@ pub enum EpochKind {
     Nanoseconds,
 }
 
-/// Represents a parsed timezone, either as a fixed offset or an IANA timezone.
+impl EpochKind {
+    /// Returns how many units of this kind make up one second.
+    pub fn units_per_second(&self) -> i64 {
+        match self {
+            EpochKind::Seconds => 1,
+            EpochKind::Milliseconds => 1_000,
+            EpochKind::Microseconds => 1_000_000,
+            EpochKind::Nanoseconds => 1_000_000_000,
+        }
+    }
+}
+
+/// The range of instants a numeric value must fall into to be detected as an epoch.
+///
+/// Bounds are inclusive. The default window spans 1900-01-01 to 2300-01-01, which covers
+/// every 10-, 13-, 16- and 19-digit epoch as well as explicitly negative (pre-1970) values,
+/// while rejecting compact dates such as `20230601100000`. Independently of the window,
+/// unsigned values shorter than 9 digits are never detected as epochs; see
+/// [`detect_epoch_kind_in`](crate::datetime::detect_epoch_kind_in).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochWindow {
+    /// The earliest accepted instant.
+    pub earliest: DateTime<Utc>,
+    /// The latest accepted instant.
+    pub latest: DateTime<Utc>,
+}
+
+impl EpochWindow {
+    /// Creates a window between two instants, inclusive.
+    pub fn new(earliest: DateTime<Utc>, latest: DateTime<Utc>) -> Self {
+        Self { earliest, latest }
+    }
+
+    /// Returns `true` if `dt` lies inside the window.
+    pub fn contains(&self, dt: &DateTime<Utc>) -> bool {
+        self.earliest <= *dt && *dt <= self.latest
+    }
+}
+
+impl Default for EpochWindow {
+    fn default() -> Self {
+        // -2208988800 is 1900-01-01T00:00:00Z, 10413792000 is 2300-01-01T00:00:00Z.
+        Self {
+            earliest: DateTime::from_timestamp(-2_208_988_800, 0).unwrap(),
+            latest: DateTime::from_timestamp(10_413_792_000, 0).unwrap(),
+        }
+    }
+}
+
+/// How numeric inputs are interpreted as epochs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EpochOption {
+    /// Detect the unit from the magnitude of the value, within the configured [`EpochWindow`].
+    #[default]
+    Auto,
+    /// Always read numeric inputs as Unix epochs in this unit, without a window check.
+    Fixed(EpochKind),
+    /// Always read numeric inputs as a count of `unit` since `origin`, without a window check.
+    Custom {
+        /// The instant the count starts from.
+        origin: DateTime<Utc>,
+        /// The unit of the count.
+        unit: EpochKind,
+    },
+    /// Always read numeric inputs, including decimals, as a timestamp of a non-Unix family.
+    Family(EpochFamily),
+}
+
+/// Numeric timestamp families with their own origin and unit, beyond Unix epochs.
+///
+/// Values may carry a decimal fraction (e.g. a Julian Day of `2460097.0833333`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochFamily {
+    /// .NET `DateTime.Ticks`: 100 ns intervals since 0001-01-01T00:00:00Z.
+    DotNetTicks,
+    /// Windows `FILETIME`: 100 ns intervals since 1601-01-01T00:00:00Z.
+    WindowsFileTime,
+    /// WebKit / Chrome timestamps: microseconds since 1601-01-01T00:00:00Z.
+    WebKit,
+    /// Apple Cocoa / Core Data `CFAbsoluteTime`: seconds since 2001-01-01T00:00:00Z.
+    Cocoa,
+    /// Mac HFS+ and MP4 `mvhd` timestamps: seconds since 1904-01-01T00:00:00Z.
+    HfsPlus,
+    /// GPS time: seconds since 1980-01-06T00:00:00Z, without leap seconds.
+    ///
+    /// The GPS-UTC leap second offset in effect (18 s since 2017) is subtracted.
+    Gps,
+    /// NTP timestamps: seconds since 1900-01-01T00:00:00Z (era 0).
+    Ntp,
+    /// Julian Day: days since -4713-11-24T12:00:00Z (proleptic Gregorian).
+    JulianDay,
+    /// Modified Julian Day: days since 1858-11-17T00:00:00Z.
+    ModifiedJulianDay,
+}
+
+/// The date system of a spreadsheet serial date number such as `45078.4166667`.
+///
+/// Serial dates count days, with the fraction giving the time of day, as local wall time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerialDateSystem {
+    /// The 1900 date system used by Excel on Windows, Lotus 1-2-3 and Google Sheets:
+    /// serial `1` is 1900-01-01.
+    ///
+    /// Like Lotus 1-2-3, it treats 1900 as a leap year, so serial `60` is the nonexistent
+    /// 1900-02-29 and later serials are shifted back by one day.
+    Excel1900,
+    /// The 1904 date system used by older Excel for Mac: serial `0` is 1904-01-01.
+    Excel1904,
+}
+
+/// How the year of an RFC 3164 syslog timestamp such as `Jun  1 10:00:00` is chosen, since the
+/// timestamp itself has none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SyslogYear {
+    /// The latest year that does not put the timestamp in the future, allowing for a few days
+    /// of clock skew.
+    #[default]
+    Current,
+    /// Like [`Current`](Self::Current), relative to the given instant instead of the current
+    /// time, e.g. the modification time of the log file being read.
+    Relative(DateTime<Utc>),
+    /// Always the given year.
+    Fixed(i32),
+}
+
+/// The syslog timestamp format an input was recognised as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyslogFormat {
+    /// A BSD syslog timestamp such as `Jun  1 10:00:00`, without year or timezone.
+    Rfc3164,
+    /// An RFC 5424 timestamp such as `2023-06-01T10:00:00.123456+07:00`.
+    Rfc5424,
+}
+
+/// Represents a parsed timezone: a fixed offset, an IANA timezone or a POSIX TZ string.
 ///
 /// `FixedOffset` is for numeric offsets like `+07:00`.
 /// `Iana` is for named timezones like `"Europe/Berlin"`.
-#[derive(Debug)]
+/// `Posix` is for rule strings like `"CET-1CEST,M3.5.0,M10.5.0/3"`.
+#[derive(Debug, Clone)]
 pub enum TimeZoneParsed {
     /// Fixed UTC offset timezone.
     FixedOffset(FixedOffset),
-    /// IANA timezone identifier.
-    Iana(Tz),
+    /// IANA timezone, with rules from a [`TimeZoneProvider`](crate::provider::TimeZoneProvider).
+    /// [`IanaZone::as_tz`] returns the matching [`chrono_tz::Tz`].
+    Iana(IanaZone),
+    /// Rule-based timezone from a POSIX TZ string.
+    Posix(PosixTz),
 }
 
 /// Represents a parsed datetime, either with a timezone (converted to UTC)
@@ -36,3 +175,126 @@ pub enum ParsedDatetime {
     /// Naive datetime without timezone.
     Naive(NaiveDateTime),
 }
+
+/// Like [`ParsedDatetime`], but keeping the offset found in the input, so that
+/// [`ParseOutcome::offset`] can report it.
+#[derive(Debug)]
+pub(crate) enum ParsedInput {
+    /// Datetime with timezone information, in the offset found in the input.
+    WithOffset(DateTime<FixedOffset>),
+    /// Naive datetime without timezone.
+    Naive(NaiveDateTime),
+}
+
+impl From<ParsedInput> for ParsedDatetime {
+    fn from(parsed: ParsedInput) -> Self {
+        match parsed {
+            ParsedInput::WithOffset(dt) => ParsedDatetime::WithTimezone(dt.with_timezone(&Utc)),
+            ParsedInput::Naive(naive) => ParsedDatetime::Naive(naive),
+        }
+    }
+}
+
+/// The preferred order of day and month when reading numeric dates such as `01-06-2045`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateOrder {
+    /// Month before day (US style), e.g. `06-01-2045` for June 1st.
+    #[default]
+    MonthFirst,
+    /// Day before month (European style), e.g. `01-06-2045` for June 1st.
+    DayFirst,
+}
+
+/// How to resolve a local time that occurs twice in a timezone, typically when clocks are
+/// turned back at the end of daylight saving time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AmbiguityPolicy {
+    /// Return [`TimeParseError::AmbiguousTime`](crate::error::TimeParseError::AmbiguousTime)
+    /// with both candidates.
+    #[default]
+    Error,
+    /// Pick the earlier of the two instants.
+    Earliest,
+    /// Pick the later of the two instants.
+    Latest,
+    /// Pick the instant on standard time; falls back to the earlier instant if both
+    /// (or neither) candidate is on standard time.
+    PreferStandard,
+    /// Pick the instant on daylight saving time; falls back to the earlier instant if both
+    /// (or neither) candidate is on daylight saving time.
+    PreferDaylight,
+}
+
+/// How to resolve a local time that does not exist in a timezone, because it falls into a gap
+/// where clocks jump forward (typically at the start of daylight saving time).
+///
+/// For example, in `Europe/Berlin` clocks jump from 02:00 to 03:00 on 2023-03-26,
+/// so `02:30` on that day does not exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapPolicy {
+    /// Return [`TimeParseError::NonexistentTime`](crate::error::TimeParseError::NonexistentTime)
+    /// describing the gap.
+    #[default]
+    Error,
+    /// Move to the first valid instant after the gap (`03:00+02:00` in the example).
+    ShiftForward,
+    /// Move to the last valid instant before the gap (`01:59:59.999999999+01:00` in the example).
+    ShiftBackward,
+    /// Interpret the local time with the offset in effect before the gap (`02:30+01:00`,
+    /// i.e. `03:30+02:00`). This matches the default of java.time and Temporal.
+    UseOffsetBefore,
+    /// Interpret the local time with the offset in effect after the gap (`02:30+02:00`,
+    /// i.e. `01:30+01:00`).
+    UseOffsetAfter,
+}
+
+/// Identifies which detector or format matched an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSource {
+    /// A numeric Unix epoch; see [`ParseOutcome::epoch_kind`] for its unit.
+    Epoch,
+    /// A numeric timestamp of a non-Unix family.
+    EpochFamily(EpochFamily),
+    /// A spreadsheet serial date number.
+    SerialDate(SerialDateSystem),
+    /// A syslog timestamp, with syslog mode enabled.
+    Syslog(SyslogFormat),
+    /// An RFC 3339 timestamp.
+    Rfc3339,
+    /// An RFC 2822 timestamp.
+    Rfc2822,
+    /// A date or date and time in the [ISO 8601](crate::iso8601::parse_iso8601) grammar.
+    Iso8601,
+    /// One of the built-in [`default_formats`](crate::formats::default_formats).
+    DefaultFormat(String),
+    /// One of the caller-supplied custom formats.
+    CustomFormat(String),
+}
+
+/// The result of a parse, together with details on how the input was interpreted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOutcome {
+    /// The normalized UTC instant.
+    pub utc: DateTime<Utc>,
+    /// The detector or format that matched.
+    pub source: ParseSource,
+    /// The UTC offset the input was interpreted in: the offset written in the input,
+    /// the one resolved in the fallback timezone, or `+00:00` for epochs.
+    pub offset: FixedOffset,
+    /// `true` if the input had no timezone information and the fallback timezone was applied.
+    pub used_fallback: bool,
+    /// The epoch unit, if the input was a numeric epoch.
+    pub epoch_kind: Option<EpochKind>,
+}
+
+impl ParseOutcome {
+    pub(crate) fn from_offset_datetime(dt: DateTime<FixedOffset>, source: ParseSource) -> Self {
+        Self {
+            utc: dt.with_timezone(&Utc),
+            source,
+            offset: *dt.offset(),
+            used_fallback: false,
+            epoch_kind: None,
+        }
+    }
+}
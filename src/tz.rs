@@ -1,7 +1,87 @@
-use chrono::{FixedOffset};
-use chrono_tz::Tz;
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::TZ_VARIANTS;
+use std::cmp::Reverse;
+use std::sync::Arc;
+use crate::abbrev::{lookup_abbreviation, resolve_abbreviation};
+use crate::country::{country_population, country_zones, resolve_country, zone1970};
 use crate::error::TimeParseError;
+use crate::posix::parse_posix_tz;
+use crate::provider::{BundledProvider, IanaZone, TimeZoneProvider};
 use crate::types::TimeZoneParsed;
+use crate::windows::windows_to_iana;
+
+/// Options for [`parse_timezone_str_with`].
+///
+/// # Examples
+///
+/// ```
+/// use utcize::tz::TimezoneOptions;
+///
+/// let options = TimezoneOptions {
+///     abbreviation_preference: vec!["IN".to_string()],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TimezoneOptions {
+    /// ISO 3166 country codes used to resolve ambiguous abbreviations such as `IST` or `CST`,
+    /// most preferred first.
+    pub abbreviation_preference: Vec<String>,
+
+    /// ISO 3166 country code used to pick the zone for a Windows time zone name, e.g. `ID`
+    /// to read `SE Asia Standard Time` as `Asia/Jakarta` rather than `Asia/Bangkok`.
+    pub windows_territory: Option<String>,
+
+    /// IANA timezones used to resolve country codes with several zones, most preferred first,
+    /// e.g. `Asia/Makassar` to read `ID` as Central Indonesia Time.
+    pub country_preference: Vec<String>,
+
+    /// Reads bare integers such as `-420` or `300` as JavaScript `getTimezoneOffset()` values:
+    /// minutes *behind* UTC, so `-420` is UTC+07:00. Only one to three digits without a
+    /// leading zero count, so `+07` and `+0700` are still UTC+07:00.
+    pub js_minute_offsets: bool,
+
+    /// Rejects deprecated IANA links such as `Asia/Calcutta` or `US/Eastern` with
+    /// [`TimeParseError::DeprecatedTimezone`] instead of accepting them.
+    pub reject_deprecated: bool,
+
+    /// Where IANA timezone rules come from, e.g. a [`TzifProvider`](crate::provider::TzifProvider)
+    /// for the system's `/usr/share/zoneinfo`. Defaults to the bundled tz database.
+    pub provider: Option<Arc<dyn TimeZoneProvider>>,
+}
+
+/// A timezone parsed by [`parse_timezone_str_detailed`], with any warnings about its name.
+#[derive(Debug, Clone)]
+pub struct ParsedTimezone {
+    /// The parsed timezone.
+    pub zone: TimeZoneParsed,
+
+    /// Warnings about the timezone name, e.g. that it is deprecated.
+    pub warnings: Vec<TimezoneWarning>,
+}
+
+/// A warning about a timezone name that was accepted but is likely a mistake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimezoneWarning {
+    /// The name is an `Etc/GMT±N` zone, whose sign follows POSIX and is inverted:
+    /// `Etc/GMT+7` is seven hours *behind* UTC.
+    InvertedEtcSign {
+        /// The timezone name.
+        name: String,
+
+        /// The actual UTC offset of the zone.
+        offset: FixedOffset,
+    },
+
+    /// The name is a deprecated link to another IANA timezone.
+    DeprecatedLink {
+        /// The deprecated name.
+        name: String,
+
+        /// The current name of the timezone.
+        replacement: String,
+    },
+}
 
 /// Parses a timezone string and returns a [`TimeZoneParsed`] enum indicating either a fixed offset
 /// or an IANA timezone (e.g., `Asia/Jakarta`, `Europe/Berlin`).
@@ -9,15 +89,39 @@ use crate::types::TimeZoneParsed;
 /// # Supported formats
 ///
 /// - `"UTC"` (case-insensitive): returns a fixed offset of `+00:00`.
-/// - Fixed offset: `+07:00`, `-0800`, etc. (parsed using [`FixedOffset`]).
-/// - IANA timezone: must be in the format `"Region/City"`.
+/// - Fixed offset: `+07:00`, `-0800`, `+7`, `+07`, `+07:07:12`, `UTC+7`, `GMT-05:30`, `Z`, etc.
+///   (see [`parse_offset`]).
+/// - Timezone abbreviation: `WIB`, `PDT`, `CEST`, etc. (see [`crate::abbrev`]), returned
+///   as a fixed offset.
+/// - Windows time zone name: `SE Asia Standard Time`, `W. Europe Standard Time`, etc.
+///   (see [`crate::windows`]), returned as the corresponding IANA timezone.
+/// - IANA timezone: any identifier known to the tz database, including single-segment and
+///   legacy names such as `CET`, `EST5EDT`, `Japan` or `Zulu`.
+/// - ISO 3166 country code: `JP`, `DE`, etc. (see [`crate::country`]), returned as the
+///   country's IANA timezone. Countries with several timezones, such as `ID`, need a
+///   [`TimezoneOptions::country_preference`].
+/// - POSIX TZ string: `CET-1CEST,M3.5.0,M10.5.0/3`, `WIB-7`, etc. (see [`crate::posix`]).
+///   Note that the sign of POSIX offsets is inverted, and that `UTC+7` or `GMT-5` are read as
+///   fixed offsets in the usual sense rather than as POSIX strings.
+///
+/// IANA identifiers take precedence over abbreviations of the same name, so `EST` and `CET`
+/// are read as the tz database zones of those names.
+///
+/// Ambiguous abbreviations such as `IST` are rejected; use [`parse_timezone_str_with`] to
+/// resolve them through a region preference.
 ///
 /// # Errors
 ///
-/// Returns [`TimeParseError::InvalidInput`] if:
-/// - The string is not a valid timezone format,
-/// - The fixed offset format is invalid,
-/// - The IANA string is malformed or unrecognized.
+/// - [`TimeParseError::MalformedOffset`] if the fixed offset format is invalid,
+/// - [`TimeParseError::OffsetOutOfRange`] if the fixed offset is 24 hours or more, or has
+///   minutes or seconds of 60 or more,
+/// - [`TimeParseError::AmbiguousAbbreviation`] if the abbreviation has several meanings,
+/// - [`TimeParseError::AmbiguousCountry`] if the country code has several timezones,
+/// - [`TimeParseError::DeprecatedTimezone`] if the name is a deprecated link and
+///   [`TimezoneOptions::reject_deprecated`] is set,
+/// - [`TimeParseError::MalformedTimezone`] if the string is not a well-formed timezone,
+/// - [`TimeParseError::UnknownTimezone`] if the IANA name is not recognized; the error lists
+///   similarly spelled timezones (see [`suggest_timezones`]).
 ///
 /// # Examples
 ///
@@ -27,44 +131,600 @@ use crate::types::TimeZoneParsed;
 /// let tz = parse_timezone_str("Asia/Jakarta").unwrap();
 /// let utc = parse_timezone_str("UTC").unwrap();
 /// let offset = parse_timezone_str("+07:00").unwrap();
+/// let wib = parse_timezone_str("WIB").unwrap();
 /// ```
 ///
 /// [`FixedOffset`]: FixedOffset
 /// [`TimeZoneParsed`]: TimeZoneParsed
-/// [`TimeParseError::InvalidInput`]: TimeParseError
 pub fn parse_timezone_str(tz_str: &str) -> Result<TimeZoneParsed, TimeParseError> {
+    parse_timezone_str_with(tz_str, &TimezoneOptions::default())
+}
+
+/// Parses a timezone string like [`parse_timezone_str`], with the given [`TimezoneOptions`].
+///
+/// # Errors
+///
+/// Same as [`parse_timezone_str`].
+///
+/// # Examples
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use utcize::tz::{parse_timezone_str_with, TimezoneOptions};
+/// use utcize::types::TimeZoneParsed;
+///
+/// let options = TimezoneOptions {
+///     abbreviation_preference: vec!["IN".to_string()],
+///     ..Default::default()
+/// };
+/// let ist = parse_timezone_str_with("IST", &options).unwrap();
+/// assert!(matches!(ist, TimeZoneParsed::FixedOffset(o) if o == FixedOffset::east_opt(19800).unwrap()));
+/// ```
+pub fn parse_timezone_str_with(
+    tz_str: &str,
+    options: &TimezoneOptions,
+) -> Result<TimeZoneParsed, TimeParseError> {
+    parse_timezone_str_detailed(tz_str, options).map(|parsed| parsed.zone)
+}
+
+/// Parses a timezone string like [`parse_timezone_str_with`], and reports warnings about
+/// names that are valid but likely mistaken, such as `Etc/GMT+7` (UTC-07:00) or deprecated links.
+///
+/// # Errors
+///
+/// Same as [`parse_timezone_str`].
+///
+/// # Examples
+///
+/// ```
+/// use utcize::tz::{parse_timezone_str_detailed, TimezoneOptions, TimezoneWarning};
+///
+/// let parsed = parse_timezone_str_detailed("Etc/GMT+7", &TimezoneOptions::default()).unwrap();
+/// match &parsed.warnings[..] {
+///     [TimezoneWarning::InvertedEtcSign { offset, .. }] => {
+///         assert_eq!(offset.local_minus_utc(), -7 * 3600)
+///     }
+///     other => panic!("unexpected warnings: {other:?}"),
+/// }
+/// ```
+pub fn parse_timezone_str_detailed(
+    tz_str: &str,
+    options: &TimezoneOptions,
+) -> Result<ParsedTimezone, TimeParseError> {
     let tz_str = tz_str.trim();
+    let zone = |zone| Ok(ParsedTimezone { zone, warnings: Vec::new() });
 
     // UTC as a special case (fallback, common usage)
     if tz_str.eq_ignore_ascii_case("UTC") {
         return FixedOffset::east_opt(0)
             .map(TimeZoneParsed::FixedOffset)
-            .ok_or_else(|| TimeParseError::InvalidInput("Invalid UTC offset".into()));
+            .ok_or_else(|| TimeParseError::MalformedOffset { input: tz_str.to_string() })
+            .and_then(zone);
+    }
+
+    // Handle fixed offset: +07:00, -0800, UTC+7, Z, etc.
+    if let Some(offset) = parse_offset_notation(tz_str, options.js_minute_offsets) {
+        return zone(TimeZoneParsed::FixedOffset(offset?));
+    }
+
+    // Any identifier known to the tz database: Asia/Jakarta, CET, EST5EDT, Japan, etc.
+    // Matched case-insensitively, so `asia/jakarta` is accepted too.
+    let provider = options.provider.as_deref().unwrap_or(&BundledProvider);
+    let known = provider.zone(tz_str).or_else(|| {
+        provider
+            .zone_names()
+            .into_iter()
+            .find(|name| name.eq_ignore_ascii_case(tz_str))
+            .and_then(|name| provider.zone(&name))
+    });
+    if let Some(tz) = known {
+        let name = tz.name();
+        let mut warnings = Vec::new();
+        if let Some(replacement) = deprecated_link_target(name) {
+            if options.reject_deprecated {
+                return Err(TimeParseError::DeprecatedTimezone {
+                    name: name.to_string(),
+                    replacement: replacement.to_string(),
+                });
+            }
+            warnings.push(TimezoneWarning::DeprecatedLink {
+                name: name.to_string(),
+                replacement: replacement.to_string(),
+            });
+        }
+        if let Some(offset) = inverted_etc_offset(name, &tz) {
+            warnings.push(TimezoneWarning::InvertedEtcSign { name: name.to_string(), offset });
+        }
+        return Ok(ParsedTimezone { zone: TimeZoneParsed::Iana(tz), warnings });
     }
 
-    // Handle fixed offset: +07:00, -0800, etc.
-    if tz_str.starts_with('+') || tz_str.starts_with('-') {
-        if let Ok(offset) = tz_str.parse::<FixedOffset>() {
-            return Ok(TimeZoneParsed::FixedOffset(offset));
-        } else {
-            return Err(TimeParseError::InvalidInput(format!(
-                "Invalid fixed offset format: '{}'", tz_str
-            )));
+    // Timezone abbreviation: WIB, PDT, CEST, etc.
+    if !lookup_abbreviation(tz_str).is_empty() {
+        let abbreviation = resolve_abbreviation(tz_str, &options.abbreviation_preference)?;
+        return zone(TimeZoneParsed::FixedOffset(abbreviation.offset()));
+    }
+
+    // Windows time zone name: SE Asia Standard Time, etc.
+    if let Some(tz) = windows_to_iana(tz_str, options.windows_territory.as_deref())
+        .and_then(|tz| provider.zone(tz.name()))
+    {
+        return zone(TimeZoneParsed::Iana(tz));
+    }
+
+    // ISO 3166 country code: JP, ID, etc.
+    if !country_zones(tz_str).is_empty() {
+        let name = resolve_country(tz_str, &options.country_preference)?;
+        if let Some(tz) = provider.zone(name) {
+            return zone(TimeZoneParsed::Iana(tz));
         }
     }
 
-    // Must follow IANA format: "Region/Location"
-    if !tz_str.contains('/') || tz_str.starts_with('/') || tz_str.ends_with('/') {
-        return Err(TimeParseError::InvalidInput(format!(
-            "Invalid IANA timezone format: '{}'", tz_str
-        )));
+    // POSIX TZ string: CET-1CEST,M3.5.0,M10.5.0/3, WIB-7, etc.
+    if let Ok(posix) = parse_posix_tz(tz_str) {
+        return zone(TimeZoneParsed::Posix(posix));
     }
 
-    // Try parse as IANA timezone
-    match tz_str.parse::<Tz>() {
-        Ok(tz) => Ok(TimeZoneParsed::Iana(tz)),
-        Err(e) => Err(TimeParseError::InvalidInput(format!(
-            "Unknown IANA timezone '{}': {}", tz_str, e
-        ))),
+    if is_well_formed_identifier(tz_str) {
+        Err(TimeParseError::UnknownTimezone {
+            name: tz_str.to_string(),
+            suggestions: suggest_timezones(tz_str),
+        })
+    } else {
+        Err(TimeParseError::MalformedTimezone { name: tz_str.to_string() })
     }
 }
+
+/// Parses a fixed UTC offset.
+///
+/// # Supported formats
+///
+/// - `+HH:MM`, `+HHMM`, `+HH`, `+H`, optionally with seconds: `+HH:MM:SS`, `+HHMMSS`
+/// - Any of the above prefixed with `UTC`, `GMT` or `UT`: `UTC+7`, `GMT-05:30`
+/// - `Z` for UTC
+///
+/// # Errors
+///
+/// - [`TimeParseError::MalformedOffset`] if the string is not an offset,
+/// - [`TimeParseError::OffsetOutOfRange`] if the offset is 24 hours or more, or has minutes
+///   or seconds of 60 or more.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::tz::parse_offset;
+///
+/// assert_eq!(parse_offset("UTC+7").unwrap().local_minus_utc(), 7 * 3600);
+/// assert_eq!(parse_offset("GMT-05:30").unwrap().local_minus_utc(), -(5 * 3600 + 30 * 60));
+/// assert_eq!(parse_offset("+07:07:12").unwrap().local_minus_utc(), 7 * 3600 + 7 * 60 + 12);
+/// assert_eq!(parse_offset("Z").unwrap().local_minus_utc(), 0);
+/// assert!(parse_offset("+25:00").is_err());
+/// ```
+pub fn parse_offset(s: &str) -> Result<FixedOffset, TimeParseError> {
+    parse_offset_notation(s.trim(), false)
+        .unwrap_or_else(|| Err(TimeParseError::MalformedOffset { input: s.trim().to_string() }))
+}
+
+/// Parses `s` as a fixed offset, or returns `None` if it does not look like one.
+///
+/// With `js_minutes`, integers of one to three digits without a leading zero, such as `-420`
+/// or `0`, are read as JavaScript `getTimezoneOffset()` minutes. Zero-padded hours such as
+/// `+07` and `+0700` keep their usual meaning.
+pub(crate) fn parse_offset_notation(
+    s: &str,
+    js_minutes: bool,
+) -> Option<Result<FixedOffset, TimeParseError>> {
+    let malformed = || TimeParseError::MalformedOffset { input: s.to_string() };
+    let out_of_range = || TimeParseError::OffsetOutOfRange { input: s.to_string() };
+
+    if s.eq_ignore_ascii_case("Z") {
+        return Some(Ok(FixedOffset::east_opt(0).expect("zero offset is in range")));
+    }
+
+    if js_minutes
+        && let Some(digits) = s.strip_prefix(['+', '-']).or(Some(s))
+        && matches!(digits.len(), 1..=3)
+        && (digits == "0" || !digits.starts_with('0'))
+        && digits.bytes().all(|b| b.is_ascii_digit())
+    {
+        let minutes: i32 = digits.parse().expect("at most three digits");
+        let minutes = if s.starts_with('-') { minutes } else { -minutes };
+        return Some(
+            minutes
+                .checked_mul(60)
+                .and_then(FixedOffset::east_opt)
+                .ok_or_else(out_of_range),
+        );
+    }
+
+    let prefixed = ["UTC", "GMT", "UT"].iter().find_map(|prefix| {
+        s.get(..prefix.len())
+            .filter(|p| p.eq_ignore_ascii_case(prefix))
+            .map(|_| &s[prefix.len()..])
+    });
+    let signed = prefixed.unwrap_or(s);
+    let (sign, body) = if let Some(body) = signed.strip_prefix('+') {
+        (1, body)
+    } else if let Some(body) = signed.strip_prefix('-') {
+        (-1, body)
+    } else {
+        return None;
+    };
+
+    let fields: Vec<&str> = if body.contains(':') {
+        body.split(':').collect()
+    } else {
+        match body.len() {
+            1 | 2 => vec![body],
+            3 => vec![&body[..1], &body[1..]],
+            4 => vec![&body[..2], &body[2..]],
+            6 => vec![&body[..2], &body[2..4], &body[4..]],
+            _ => return Some(Err(malformed())),
+        }
+    };
+    let valid = matches!(fields.len(), 1..=3)
+        && fields.iter().enumerate().all(|(i, field)| {
+            let width = if i == 0 { 1..=2 } else { 2..=2 };
+            width.contains(&field.len()) && field.bytes().all(|b| b.is_ascii_digit())
+        });
+    if !valid {
+        return Some(Err(malformed()));
+    }
+
+    let mut parts = fields.iter().map(|f| f.parse::<i32>().unwrap_or_default());
+    let (hours, minutes, seconds) = (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    );
+    if minutes >= 60 || seconds >= 60 {
+        return Some(Err(out_of_range()));
+    }
+    Some(
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60 + seconds))
+            .ok_or_else(out_of_range),
+    )
+}
+
+/// Splits a trailing UTC offset off a datetime string, e.g. `2023-06-01 10:00:00 UTC+7`,
+/// `2023-06-01 10:00:00+7` or `2023-06-01 10:00:00Z`.
+///
+/// Only considered when the remainder contains a time (`:`), so the fields of a date such as
+/// `2023-06-01` are never mistaken for an offset.
+pub(crate) fn split_trailing_offset(
+    s: &str,
+    js_minutes: bool,
+) -> Option<(&str, Result<FixedOffset, TimeParseError>)> {
+    let (rest, offset) = match s.rsplit_once(char::is_whitespace) {
+        // A bare integer is a year or a time field, not an offset.
+        Some((rest, last)) if !last.starts_with(|c: char| c.is_ascii_digit()) => (rest, last),
+        _ if s.ends_with(['Z', 'z']) => s.split_at(s.len() - 1),
+        _ => s.split_at(s.rfind(['+', '-'])?),
+    };
+    let rest = rest.trim_end();
+
+    if !rest.contains(':') || !rest.ends_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    parse_offset_notation(offset, js_minutes).map(|offset| (rest, offset))
+}
+
+/// Returns up to five known IANA timezones spelled similarly to `name`, closest first.
+///
+/// Names are compared case-insensitively, both in full and by their last segment, so a
+/// misspelled city such as `Jakatra` still finds `Asia/Jakarta`.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::tz::suggest_timezones;
+///
+/// assert_eq!(suggest_timezones("Asia/Jakatra")[0], "Asia/Jakarta");
+/// assert_eq!(suggest_timezones("Jakatra")[0], "Asia/Jakarta");
+/// assert!(suggest_timezones("Qwertyuiop/Asdfgh").is_empty());
+/// ```
+pub fn suggest_timezones(name: &str) -> Vec<String> {
+    let name = name.to_ascii_lowercase();
+    let city = |s: &str| s.rsplit('/').next().unwrap_or(s).to_string();
+    let max_distance = (name.len() / 4).max(2);
+
+    let mut candidates: Vec<(usize, &str)> = TZ_VARIANTS
+        .iter()
+        .filter_map(|tz| {
+            let known = tz.name().to_ascii_lowercase();
+            let distance = if name.contains('/') {
+                edit_distance(&name, &known)
+            } else {
+                edit_distance(&name, &known).min(edit_distance(&name, &city(&known)))
+            };
+            (distance <= max_distance).then_some((distance, tz.name()))
+        })
+        .collect();
+    candidates.sort();
+    candidates.into_iter().take(5).map(|(_, name)| name.to_string()).collect()
+}
+
+/// Returns the IANA timezones whose UTC offset at the instant of `dt` is the offset of `dt`,
+/// most likely first.
+///
+/// Useful to upgrade a fixed offset such as `+07:00` to a real timezone before doing local
+/// time arithmetic. Zones used in `country_hint` (an ISO 3166 code) come first; otherwise zones
+/// are ranked by the population of the country they mainly serve, then by the tz database
+/// order within that country, which puts the most populous regions first. Only the zones of
+/// `zone1970.tab` are considered, so links and `Etc/GMT` zones are never returned.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::DateTime;
+/// use utcize::tz::candidate_zones;
+///
+/// let dt = DateTime::parse_from_rfc3339("2023-06-01T10:00:00+07:00").unwrap();
+/// assert_eq!(candidate_zones(&dt, None)[0].name(), "Asia/Jakarta");
+/// assert_eq!(candidate_zones(&dt, Some("TH"))[0].name(), "Asia/Bangkok");
+/// ```
+pub fn candidate_zones(dt: &DateTime<FixedOffset>, country_hint: Option<&str>) -> Vec<IanaZone> {
+    candidate_zones_with(dt, country_hint, &BundledProvider)
+}
+
+/// Returns candidate timezones like [`candidate_zones`], with rules from the given provider.
+///
+/// Zones the provider does not know are skipped.
+pub fn candidate_zones_with(
+    dt: &DateTime<FixedOffset>,
+    country_hint: Option<&str>,
+    provider: &dyn TimeZoneProvider,
+) -> Vec<IanaZone> {
+    let utc = dt.naive_utc();
+    let mut candidates: Vec<(bool, Reverse<u32>, usize, IanaZone)> = zone1970()
+        .enumerate()
+        .filter_map(|(index, (countries, name))| {
+            let zone = provider.zone(name)?;
+            if zone.offset_at(&utc).utc_offset != *dt.offset() {
+                return None;
+            }
+            let mut countries = countries.split(',');
+            let hinted = country_hint
+                .is_some_and(|hint| countries.clone().any(|c| c.eq_ignore_ascii_case(hint)));
+            let population = country_population(countries.next().unwrap_or_default());
+            Some((!hinted, Reverse(population), index, zone))
+        })
+        .collect();
+    candidates.sort_by_key(|(unhinted, population, index, _)| (*unhinted, *population, *index));
+    candidates.into_iter().map(|(.., zone)| zone).collect()
+}
+
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Returns the current name of a deprecated IANA link, e.g. `Asia/Kolkata` for `Asia/Calcutta`.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::tz::deprecated_link_target;
+///
+/// assert_eq!(deprecated_link_target("US/Eastern"), Some("America/New_York"));
+/// assert_eq!(deprecated_link_target("America/New_York"), None);
+/// ```
+pub fn deprecated_link_target(name: &str) -> Option<&'static str> {
+    DEPRECATED_LINKS
+        .iter()
+        .find(|(link, _)| *link == name)
+        .map(|(_, target)| *target)
+}
+
+/// Returns the actual UTC offset of an `Etc/GMT±N` zone with a non-zero `N`.
+fn inverted_etc_offset(name: &str, tz: &IanaZone) -> Option<FixedOffset> {
+    let n = name.strip_prefix("Etc/GMT")?;
+    if !n.starts_with(['+', '-']) || n[1..].trim_start_matches('0').is_empty() {
+        return None;
+    }
+    Some(tz.offset_at(&DateTime::UNIX_EPOCH.naive_utc()).utc_offset)
+}
+
+/// Returns `true` if `name` looks like a tz database identifier: `/`-separated segments of
+/// ASCII letters, digits, `_`, `-` and `+`, starting with a letter.
+pub(crate) fn is_well_formed_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.split('/').all(|segment| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+        })
+}
+
+/// Splits a leading or trailing IANA timezone off a datetime string, e.g.
+/// `2023-06-01 10:00:00 Asia/Jakarta` or `Europe/Berlin 01.06.2023 10:00`.
+///
+/// Only `Region/City` style names are considered, so dates such as `01/06/2023` are left alone.
+pub(crate) fn split_inline_zone<'a>(
+    s: &'a str,
+    provider: &dyn TimeZoneProvider,
+) -> Option<(&'a str, IanaZone)> {
+    let as_zone = |token: &str| token.contains('/').then(|| provider.zone(token)).flatten();
+
+    if let Some((rest, last)) = s.rsplit_once(char::is_whitespace)
+        && let Some(tz) = as_zone(last)
+    {
+        return Some((rest.trim_end(), tz));
+    }
+    if let Some((first, rest)) = s.split_once(char::is_whitespace)
+        && let Some(tz) = as_zone(first)
+    {
+        return Some((rest.trim_start(), tz));
+    }
+    None
+}
+
+/// Deprecated IANA links and their current names, from the tz database `backward` file.
+///
+/// Only backward-compatibility aliases are listed. Zones that the tz database merged into
+/// another zone (e.g. `Europe/Oslo` into `Europe/Berlin`) are still the `zone.tab` names of
+/// their countries, so they are not deprecated, and aliases point to those names.
+static DEPRECATED_LINKS: &[(&str, &str)] = &[
+    ("Australia/ACT", "Australia/Sydney"),
+    ("Australia/LHI", "Australia/Lord_Howe"),
+    ("Australia/NSW", "Australia/Sydney"),
+    ("Australia/North", "Australia/Darwin"),
+    ("Australia/Queensland", "Australia/Brisbane"),
+    ("Australia/South", "Australia/Adelaide"),
+    ("Australia/Tasmania", "Australia/Hobart"),
+    ("Australia/Victoria", "Australia/Melbourne"),
+    ("Australia/West", "Australia/Perth"),
+    ("Australia/Yancowinna", "Australia/Broken_Hill"),
+    ("Brazil/Acre", "America/Rio_Branco"),
+    ("Brazil/DeNoronha", "America/Noronha"),
+    ("Brazil/East", "America/Sao_Paulo"),
+    ("Brazil/West", "America/Manaus"),
+    ("CET", "Europe/Brussels"),
+    ("CST6CDT", "America/Chicago"),
+    ("Canada/Atlantic", "America/Halifax"),
+    ("Canada/Central", "America/Winnipeg"),
+    ("Canada/Eastern", "America/Toronto"),
+    ("Canada/Mountain", "America/Edmonton"),
+    ("Canada/Newfoundland", "America/St_Johns"),
+    ("Canada/Pacific", "America/Vancouver"),
+    ("Canada/Saskatchewan", "America/Regina"),
+    ("Canada/Yukon", "America/Whitehorse"),
+    ("Chile/Continental", "America/Santiago"),
+    ("Chile/EasterIsland", "Pacific/Easter"),
+    ("Cuba", "America/Havana"),
+    ("EET", "Europe/Athens"),
+    ("EST", "America/Panama"),
+    ("EST5EDT", "America/New_York"),
+    ("Egypt", "Africa/Cairo"),
+    ("Eire", "Europe/Dublin"),
+    ("Etc/GMT+0", "Etc/GMT"),
+    ("Etc/GMT-0", "Etc/GMT"),
+    ("Etc/GMT0", "Etc/GMT"),
+    ("Etc/Greenwich", "Etc/GMT"),
+    ("Etc/UCT", "Etc/UTC"),
+    ("Etc/Universal", "Etc/UTC"),
+    ("Etc/Zulu", "Etc/UTC"),
+    ("GB", "Europe/London"),
+    ("GB-Eire", "Europe/London"),
+    ("GMT+0", "Etc/GMT"),
+    ("GMT-0", "Etc/GMT"),
+    ("GMT0", "Etc/GMT"),
+    ("Greenwich", "Etc/GMT"),
+    ("Hongkong", "Asia/Hong_Kong"),
+    ("Iceland", "Atlantic/Reykjavik"),
+    ("Iran", "Asia/Tehran"),
+    ("Israel", "Asia/Jerusalem"),
+    ("Jamaica", "America/Jamaica"),
+    ("Japan", "Asia/Tokyo"),
+    ("Kwajalein", "Pacific/Kwajalein"),
+    ("Libya", "Africa/Tripoli"),
+    ("MET", "Europe/Brussels"),
+    ("MST", "America/Phoenix"),
+    ("MST7MDT", "America/Denver"),
+    ("Mexico/BajaNorte", "America/Tijuana"),
+    ("Mexico/BajaSur", "America/Mazatlan"),
+    ("Mexico/General", "America/Mexico_City"),
+    ("NZ", "Pacific/Auckland"),
+    ("NZ-CHAT", "Pacific/Chatham"),
+    ("Navajo", "America/Denver"),
+    ("PRC", "Asia/Shanghai"),
+    ("Poland", "Europe/Warsaw"),
+    ("Portugal", "Europe/Lisbon"),
+    ("ROC", "Asia/Taipei"),
+    ("ROK", "Asia/Seoul"),
+    ("Singapore", "Asia/Singapore"),
+    ("Turkey", "Europe/Istanbul"),
+    ("UCT", "Etc/UTC"),
+    ("US/Alaska", "America/Anchorage"),
+    ("US/Aleutian", "America/Adak"),
+    ("US/Arizona", "America/Phoenix"),
+    ("US/Central", "America/Chicago"),
+    ("US/East-Indiana", "America/Indiana/Indianapolis"),
+    ("US/Eastern", "America/New_York"),
+    ("US/Hawaii", "Pacific/Honolulu"),
+    ("US/Indiana-Starke", "America/Indiana/Knox"),
+    ("US/Michigan", "America/Detroit"),
+    ("US/Mountain", "America/Denver"),
+    ("US/Pacific", "America/Los_Angeles"),
+    ("US/Samoa", "Pacific/Pago_Pago"),
+    ("UTC", "Etc/UTC"),
+    ("Universal", "Etc/UTC"),
+    ("W-SU", "Europe/Moscow"),
+    ("Zulu", "Etc/UTC"),
+    ("America/Buenos_Aires", "America/Argentina/Buenos_Aires"),
+    ("America/Catamarca", "America/Argentina/Catamarca"),
+    ("America/Cordoba", "America/Argentina/Cordoba"),
+    ("America/Indianapolis", "America/Indiana/Indianapolis"),
+    ("America/Jujuy", "America/Argentina/Jujuy"),
+    ("America/Knox_IN", "America/Indiana/Knox"),
+    ("America/Louisville", "America/Kentucky/Louisville"),
+    ("America/Mendoza", "America/Argentina/Mendoza"),
+    ("America/Virgin", "America/St_Thomas"),
+    ("Pacific/Samoa", "Pacific/Pago_Pago"),
+    ("Africa/Timbuktu", "Africa/Bamako"),
+    ("America/Argentina/ComodRivadavia", "America/Argentina/Catamarca"),
+    ("America/Atka", "America/Adak"),
+    ("America/Coral_Harbour", "America/Atikokan"),
+    ("America/Ensenada", "America/Tijuana"),
+    ("America/Fort_Wayne", "America/Indiana/Indianapolis"),
+    ("America/Montreal", "America/Toronto"),
+    ("America/Nipigon", "America/Toronto"),
+    ("America/Pangnirtung", "America/Iqaluit"),
+    ("America/Porto_Acre", "America/Rio_Branco"),
+    ("America/Rainy_River", "America/Winnipeg"),
+    ("America/Rosario", "America/Argentina/Cordoba"),
+    ("America/Santa_Isabel", "America/Tijuana"),
+    ("America/Shiprock", "America/Denver"),
+    ("America/Thunder_Bay", "America/Toronto"),
+    ("America/Yellowknife", "America/Edmonton"),
+    ("Antarctica/South_Pole", "Antarctica/McMurdo"),
+    ("Asia/Choibalsan", "Asia/Ulaanbaatar"),
+    ("Asia/Chongqing", "Asia/Shanghai"),
+    ("Asia/Harbin", "Asia/Shanghai"),
+    ("Asia/Kashgar", "Asia/Urumqi"),
+    ("Asia/Tel_Aviv", "Asia/Jerusalem"),
+    ("Atlantic/Jan_Mayen", "Europe/Oslo"),
+    ("Australia/Canberra", "Australia/Sydney"),
+    ("Australia/Currie", "Australia/Hobart"),
+    ("Europe/Belfast", "Europe/London"),
+    ("Europe/Tiraspol", "Europe/Chisinau"),
+    ("Europe/Uzhgorod", "Europe/Kyiv"),
+    ("Europe/Zaporozhye", "Europe/Kyiv"),
+    ("Pacific/Enderbury", "Pacific/Kanton"),
+    ("Pacific/Johnston", "Pacific/Honolulu"),
+    ("Pacific/Yap", "Pacific/Chuuk"),
+    ("WET", "Europe/Lisbon"),
+    ("Africa/Asmera", "Africa/Asmara"),
+    ("America/Godthab", "America/Nuuk"),
+    ("Asia/Ashkhabad", "Asia/Ashgabat"),
+    ("Asia/Calcutta", "Asia/Kolkata"),
+    ("Asia/Chungking", "Asia/Shanghai"),
+    ("Asia/Dacca", "Asia/Dhaka"),
+    ("Asia/Istanbul", "Europe/Istanbul"),
+    ("Asia/Katmandu", "Asia/Kathmandu"),
+    ("Asia/Macao", "Asia/Macau"),
+    ("Asia/Rangoon", "Asia/Yangon"),
+    ("Asia/Saigon", "Asia/Ho_Chi_Minh"),
+    ("Asia/Thimbu", "Asia/Thimphu"),
+    ("Asia/Ujung_Pandang", "Asia/Makassar"),
+    ("Asia/Ulan_Bator", "Asia/Ulaanbaatar"),
+    ("Atlantic/Faeroe", "Atlantic/Faroe"),
+    ("Europe/Kiev", "Europe/Kyiv"),
+    ("Europe/Nicosia", "Asia/Nicosia"),
+    ("HST", "Pacific/Honolulu"),
+    ("PST8PDT", "America/Los_Angeles"),
+    ("Pacific/Ponape", "Pacific/Pohnpei"),
+    ("Pacific/Truk", "Pacific/Chuuk"),
+];
@@ -0,0 +1,685 @@
+use crate::abbrev::{resolve_abbreviation, split_attached_letters, split_trailing_abbreviation};
+use crate::datetime::{
+    detect_epoch_kind_in, epoch_family_to_utc, epoch_to_utc_from, parse_epoch_value,
+    serial_date_to_naive, split_epoch_suffix,
+};
+use crate::error::TimeParseError;
+use crate::formats::{ClosestMatch, CompiledFormats, unmatched_by};
+use crate::iso8601::parse_iso8601_tracking;
+use crate::locale::{Locale, translate_names};
+use crate::provider::{BundledProvider, LocalOffsets, TimeZoneProvider, ZoneRules};
+use crate::syslog::parse_syslog_tracking;
+use crate::types::{
+    AmbiguityPolicy, DateOrder, EpochKind, EpochOption, EpochWindow, GapPolicy, ParseOutcome,
+    ParseSource, ParsedInput, SerialDateSystem, SyslogYear, TimeZoneParsed,
+};
+use crate::tz::{split_inline_zone, split_trailing_offset};
+use chrono::format::Fixed;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeDelta, TimeZone, Utc};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// A reusable, pre-configured datetime normalizer.
+///
+/// A `Utcizer` holds everything [`utcize`](crate::datetime::utcize) otherwise takes as
+/// positional arguments: the fallback timezone (already parsed), the preferred date order
+/// and any custom formats, compiled once. Build it once with [`Utcizer::builder`] and call
+/// [`parse`](Utcizer::parse) as often as needed.
+///
+/// `Utcizer` is `Send + Sync`, so a single instance can be shared between threads,
+/// for example behind an [`Arc`](std::sync::Arc).
+///
+/// # Examples
+///
+/// ```
+/// use utcize::tz::parse_timezone_str;
+/// use utcize::types::DateOrder;
+/// use utcize::utcizer::Utcizer;
+///
+/// let utcizer = Utcizer::builder()
+///     .fallback_tz(parse_timezone_str("Asia/Jakarta").unwrap())
+///     .date_order(DateOrder::DayFirst)
+///     .custom_formats(["%Y|%m|%d %H:%M"])
+///     .build();
+///
+/// let dt = utcizer.parse("01-06-2023 10:00:00").unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+///
+/// let dt = utcizer.parse("2023|06|01 10:00").unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Utcizer {
+    fallback_tz: TimeZoneParsed,
+    date_order: DateOrder,
+    custom_formats: CompiledFormats,
+    ambiguity_policy: AmbiguityPolicy,
+    gap_policy: GapPolicy,
+    epoch: EpochOption,
+    epoch_window: EpochWindow,
+    serial_dates: Option<SerialDateSystem>,
+    syslog: Option<SyslogYear>,
+    abbreviation_preference: Vec<String>,
+    js_minute_offsets: bool,
+    locales: Vec<Locale>,
+    timezone_provider: Arc<dyn TimeZoneProvider>,
+}
+
+impl Utcizer {
+    /// Returns a new [`UtcizerBuilder`] with default settings.
+    pub fn builder() -> UtcizerBuilder {
+        UtcizerBuilder::default()
+    }
+
+    /// Returns the timezone used for inputs without timezone information.
+    pub fn fallback_tz(&self) -> &TimeZoneParsed {
+        &self.fallback_tz
+    }
+
+    /// Returns the preferred date order for ambiguous numeric dates.
+    pub fn date_order(&self) -> DateOrder {
+        self.date_order
+    }
+
+    /// Returns the policy for local times that occur twice in the fallback timezone.
+    pub fn ambiguity_policy(&self) -> AmbiguityPolicy {
+        self.ambiguity_policy
+    }
+
+    /// Returns the policy for local times that fall into a gap of the fallback timezone.
+    pub fn gap_policy(&self) -> GapPolicy {
+        self.gap_policy
+    }
+
+    /// Returns how numeric inputs are interpreted as epochs.
+    pub fn epoch(&self) -> EpochOption {
+        self.epoch
+    }
+
+    /// Returns the window numeric inputs must fall into to be detected as epochs.
+    pub fn epoch_window(&self) -> &EpochWindow {
+        &self.epoch_window
+    }
+
+    /// Returns the date system used for spreadsheet serial dates, if enabled.
+    pub fn serial_dates(&self) -> Option<SerialDateSystem> {
+        self.serial_dates
+    }
+
+    /// Returns how the year of RFC 3164 syslog timestamps is chosen, if syslog mode is enabled.
+    pub fn syslog(&self) -> Option<SyslogYear> {
+        self.syslog
+    }
+
+    /// Returns the countries used to resolve ambiguous timezone abbreviations.
+    pub fn abbreviation_preference(&self) -> &[String] {
+        &self.abbreviation_preference
+    }
+
+    /// Returns `true` if integer offset suffixes are read as JavaScript minutes.
+    pub fn js_minute_offsets(&self) -> bool {
+        self.js_minute_offsets
+    }
+
+    /// Returns the languages whose month and weekday names are recognised, in order.
+    pub fn locales(&self) -> &[Locale] {
+        &self.locales
+    }
+
+    /// Returns the provider used to look up IANA timezones named in the input.
+    pub fn timezone_provider(&self) -> &dyn TimeZoneProvider {
+        self.timezone_provider.as_ref()
+    }
+
+    /// Returns the compiled custom formats tried before the default formats.
+    pub fn custom_formats(&self) -> &CompiledFormats {
+        &self.custom_formats
+    }
+
+    /// Parses a datetime string into a `DateTime<Utc>`.
+    ///
+    /// The input is tried, in order, as a spreadsheet serial date or a syslog timestamp
+    /// (if enabled), a Unix epoch,
+    /// RFC 3339, RFC 2822, the custom formats, ISO 8601 and finally the default formats.
+    /// Naive results are resolved in the fallback timezone, unless the input names its own
+    /// IANA timezone or abbreviation (e.g. `2023-06-01 10:00:00 Asia/Jakarta`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeParseError`] if no format matches, the epoch is out of range,
+    /// or the local time is ambiguous or nonexistent in the fallback timezone.
+    pub fn parse(&self, s: &str) -> Result<DateTime<Utc>, TimeParseError> {
+        self.parse_detailed(s).map(|outcome| outcome.utc)
+    }
+
+    /// Parses a datetime string like [`parse`](Self::parse), and reports how it was interpreted.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`parse`](Self::parse).
+    pub fn parse_detailed(&self, s: &str) -> Result<ParseOutcome, TimeParseError> {
+        self.parse_detailed_in(s, || Ok(Cow::Borrowed(&self.fallback_tz)))
+    }
+
+    /// Parses like [`parse_detailed`](Self::parse_detailed), resolving naive results in the
+    /// timezone returned by `fallback_tz` instead of the configured fallback timezone.
+    ///
+    /// `fallback_tz` is only called if the result is naive, so an invalid fallback timezone
+    /// does not fail inputs that carry their own.
+    pub(crate) fn parse_detailed_in<'a>(
+        &self,
+        s: &str,
+        fallback_tz: impl FnOnce() -> Result<Cow<'a, TimeZoneParsed>, TimeParseError>,
+    ) -> Result<ParseOutcome, TimeParseError> {
+        let s = s.trim();
+
+        // === Spreadsheet serial date ===
+        if let Some(system) = self.serial_dates
+            && let Some(naive) = serial_date_to_naive(s, system)
+        {
+            let dt = self.resolve_local(naive?, &*fallback_tz()?)?;
+            return Ok(ParseOutcome {
+                used_fallback: true,
+                ..ParseOutcome::from_offset_datetime(dt, ParseSource::SerialDate(system))
+            });
+        }
+
+        // === Syslog timestamp ===
+        if let Some(year) = self.syslog
+            && let Some(result) = parse_syslog_tracking(s, year)
+        {
+            let (parsed, format) = result?;
+            let source = ParseSource::Syslog(format);
+            return match parsed {
+                ParsedInput::WithOffset(dt) => Ok(ParseOutcome::from_offset_datetime(dt, source)),
+                ParsedInput::Naive(naive) => Ok(ParseOutcome {
+                    used_fallback: true,
+                    ..ParseOutcome::from_offset_datetime(
+                        self.resolve_local(naive, &*fallback_tz()?)?,
+                        source,
+                    )
+                }),
+            };
+        }
+
+        // === Epoch numeric ===
+        if let EpochOption::Family(family) = self.epoch
+            && let Some(result) = epoch_family_to_utc(s, family)
+        {
+            return Ok(ParseOutcome {
+                utc: result?,
+                source: ParseSource::EpochFamily(family),
+                offset: Utc.fix(),
+                used_fallback: false,
+                epoch_kind: None,
+            });
+        }
+        if let Some((num, kind)) = self.detect_epoch(s) {
+            let origin = match self.epoch {
+                EpochOption::Custom { origin, .. } => origin,
+                _ => DateTime::UNIX_EPOCH,
+            };
+            let dt = epoch_to_utc_from(origin, num, kind)
+                .ok_or(TimeParseError::EpochOutOfRange { value: num, kind })?;
+            return Ok(ParseOutcome {
+                utc: dt,
+                source: ParseSource::Epoch,
+                offset: Utc.fix(),
+                used_fallback: false,
+                epoch_kind: Some(kind),
+            });
+        }
+
+        // === RFC 3339 / 2822 ===
+        let mut closest = ClosestMatch::default();
+        match DateTime::parse_from_rfc3339(s) {
+            Ok(dt) => return Ok(ParseOutcome::from_offset_datetime(dt, ParseSource::Rfc3339)),
+            Err(e) => closest.record("RFC 3339", e.kind(), unmatched_by(s, Fixed::RFC3339)),
+        }
+        match DateTime::parse_from_rfc2822(s) {
+            Ok(dt) => return Ok(ParseOutcome::from_offset_datetime(dt, ParseSource::Rfc2822)),
+            Err(e) => closest.record("RFC 2822", e.kind(), unmatched_by(s, Fixed::RFC2822)),
+        }
+
+        // === Custom / Flexible format ===
+        let Some((parsed, source)) = self.match_formats(s, &mut closest)? else {
+            return Err(closest.into_error(s));
+        };
+
+        match parsed {
+            ParsedInput::WithOffset(dt) => Ok(ParseOutcome::from_offset_datetime(dt, source)),
+            ParsedInput::Naive(naive) => {
+                let dt = self.resolve_local(naive, &*fallback_tz()?)?;
+                Ok(ParseOutcome {
+                    used_fallback: true,
+                    ..ParseOutcome::from_offset_datetime(dt, source)
+                })
+            }
+        }
+    }
+
+    /// Matches `s` against the custom formats, then the default formats.
+    ///
+    /// A leading or trailing IANA timezone, or a trailing timezone abbreviation, is split off
+    /// first and applied to a naive match of the remainder, taking precedence over the fallback
+    /// timezone. If the remainder matches nothing, the whole string is tried instead, then the
+    /// string without a trailing offset such as `UTC+7`, `+7` or `Z`. Finally, letters attached
+    /// to the last digit that are not a known abbreviation (`20230601100000XYZ`) are reported
+    /// as an unknown timezone.
+    pub(crate) fn match_formats(
+        &self,
+        s: &str,
+        closest: &mut ClosestMatch,
+    ) -> Result<Option<(ParsedInput, ParseSource)>, TimeParseError> {
+        if let Some((rest, tz)) = split_inline_zone(s, self.timezone_provider.as_ref())
+            && let Some((parsed, source)) = self.try_formats(rest, &mut ClosestMatch::default())
+        {
+            let parsed = match parsed {
+                ParsedInput::Naive(naive) => {
+                    ParsedInput::WithOffset(self.resolve_local(naive, &TimeZoneParsed::Iana(tz))?)
+                }
+                with_timezone => with_timezone,
+            };
+            return Ok(Some((parsed, source)));
+        }
+
+        if let Some((rest, abbr)) = split_trailing_abbreviation(s)
+            && let Some((parsed, source)) = self.try_formats(rest, &mut ClosestMatch::default())
+        {
+            let parsed = match parsed {
+                ParsedInput::Naive(naive) => {
+                    let abbreviation = resolve_abbreviation(abbr, &self.abbreviation_preference)?;
+                    abbreviation.check_date(&naive)?;
+                    ParsedInput::WithOffset(with_offset(naive, abbreviation.offset())?)
+                }
+                with_timezone => with_timezone,
+            };
+            return Ok(Some((parsed, source)));
+        }
+
+        if let Some(matched) = self.try_formats(s, closest) {
+            return Ok(Some(matched));
+        }
+
+        if let Some((rest, offset)) = split_trailing_offset(s, self.js_minute_offsets)
+            && let Some((ParsedInput::Naive(naive), source)) =
+                self.try_formats(rest, &mut ClosestMatch::default())
+        {
+            let dt = with_offset(naive, offset?)?;
+            return Ok(Some((ParsedInput::WithOffset(dt), source)));
+        }
+
+        // Only now, so that formats with `%p` can still read `10:00PM`.
+        if let Some((rest, abbr)) = split_attached_letters(s)
+            && self
+                .try_formats(rest, &mut ClosestMatch::default())
+                .is_some()
+        {
+            return Err(TimeParseError::UnknownTimezone {
+                name: abbr.to_string(),
+                suggestions: Vec::new(),
+            });
+        }
+
+        Ok(None)
+    }
+
+    /// Tries the custom formats, then the ISO 8601 grammar, then the default formats, on the
+    /// whole of `s`, and once more with localized month and weekday names translated to English.
+    fn try_formats(
+        &self,
+        s: &str,
+        closest: &mut ClosestMatch,
+    ) -> Option<(ParsedInput, ParseSource)> {
+        if let Some(matched) = self.try_formats_exact(s, closest) {
+            return Some(matched);
+        }
+        let translated = translate_names(s, &self.locales)?;
+        self.try_formats_exact(&translated, closest)
+    }
+
+    /// Tries the custom formats, then the ISO 8601 grammar, then the default formats, on `s` as is.
+    fn try_formats_exact(
+        &self,
+        s: &str,
+        closest: &mut ClosestMatch,
+    ) -> Option<(ParsedInput, ParseSource)> {
+        if let Some((parsed, fmt)) = self.custom_formats.parse_tracking(s, closest) {
+            return Some((parsed, ParseSource::CustomFormat(fmt.as_str().to_string())));
+        }
+
+        if let Some(parsed) = parse_iso8601_tracking(s, closest) {
+            return Some((parsed, ParseSource::Iso8601));
+        }
+
+        let prefer_eu = self.date_order == DateOrder::DayFirst;
+        CompiledFormats::defaults(prefer_eu)
+            .parse_tracking(s, closest)
+            .map(|(parsed, fmt)| (parsed, ParseSource::DefaultFormat(fmt.as_str().to_string())))
+    }
+
+    /// Returns the value and unit of `s` if it should be read as an epoch.
+    ///
+    /// An explicit unit suffix always wins over the configured [`EpochOption`], and is read
+    /// as a Unix epoch even when an [`EpochFamily`](crate::types::EpochFamily) is configured.
+    fn detect_epoch(&self, s: &str) -> Option<(i64, EpochKind)> {
+        let (num, suffix) = match split_epoch_suffix(s) {
+            Some((num, kind)) => (num, Some(kind)),
+            None => (parse_epoch_value(s)?, None),
+        };
+
+        let kind = match self.epoch {
+            EpochOption::Auto => suffix.or_else(|| detect_epoch_kind_in(num, &self.epoch_window)),
+            EpochOption::Fixed(unit) | EpochOption::Custom { unit, .. } => {
+                Some(suffix.unwrap_or(unit))
+            }
+            EpochOption::Family(_) => suffix,
+        }?;
+        Some((num, kind))
+    }
+
+    /// Resolves a naive local datetime in `tz`, applying the ambiguity and gap policies.
+    fn resolve_local(
+        &self,
+        naive: NaiveDateTime,
+        tz: &TimeZoneParsed,
+    ) -> Result<DateTime<FixedOffset>, TimeParseError> {
+        match tz {
+            TimeZoneParsed::FixedOffset(offset) => Ok(in_offset(naive, *offset)),
+            TimeZoneParsed::Iana(tz) => self.resolve_rules(naive, tz),
+            TimeZoneParsed::Posix(tz) => self.resolve_rules(naive, tz),
+        }
+    }
+
+    /// Resolves a naive local datetime in a rule-based timezone, applying the ambiguity and
+    /// gap policies.
+    fn resolve_rules(
+        &self,
+        naive: NaiveDateTime,
+        tz: &dyn ZoneRules,
+    ) -> Result<DateTime<FixedOffset>, TimeParseError> {
+        match tz.local_offsets(&naive) {
+            LocalOffsets::Single(offset) => Ok(in_offset(naive, offset.utc_offset)),
+            LocalOffsets::Ambiguous(earliest, latest) => {
+                let chosen = match self.ambiguity_policy {
+                    AmbiguityPolicy::Error => {
+                        return Err(TimeParseError::AmbiguousTime {
+                            datetime: naive,
+                            options: vec![
+                                in_offset(naive, earliest.utc_offset).with_timezone(&Utc),
+                                in_offset(naive, latest.utc_offset).with_timezone(&Utc),
+                            ],
+                        });
+                    }
+                    AmbiguityPolicy::Earliest => earliest,
+                    AmbiguityPolicy::Latest => latest,
+                    AmbiguityPolicy::PreferStandard if earliest.is_dst && !latest.is_dst => latest,
+                    AmbiguityPolicy::PreferDaylight if !earliest.is_dst && latest.is_dst => latest,
+                    AmbiguityPolicy::PreferStandard | AmbiguityPolicy::PreferDaylight => earliest,
+                };
+                Ok(in_offset(naive, chosen.utc_offset))
+            }
+            LocalOffsets::Gap {
+                before,
+                after,
+                transition,
+            } => self.resolve_gap(naive, tz, before.utc_offset, after.utc_offset, transition),
+        }
+    }
+
+    /// Resolves a local time that falls into a gap of a rule-based timezone, caused by the
+    /// transition from `before` to `after` at the UTC instant `transition`.
+    fn resolve_gap(
+        &self,
+        naive: NaiveDateTime,
+        tz: &dyn ZoneRules,
+        before: FixedOffset,
+        after: FixedOffset,
+        transition: NaiveDateTime,
+    ) -> Result<DateTime<FixedOffset>, TimeParseError> {
+        let gap_end = after.from_utc_datetime(&transition);
+
+        let shifted = match self.gap_policy {
+            GapPolicy::Error => None,
+            GapPolicy::ShiftForward => Some(gap_end),
+            GapPolicy::ShiftBackward => {
+                Some(gap_end.with_timezone(&before) - TimeDelta::nanoseconds(1))
+            }
+            GapPolicy::UseOffsetBefore => Some(in_offset(naive, before)),
+            GapPolicy::UseOffsetAfter => Some(in_offset(naive, after)),
+        };
+
+        shifted.ok_or_else(|| TimeParseError::NonexistentTime {
+            datetime: naive,
+            tz: tz.name().to_string(),
+            gap_start: before.from_utc_datetime(&transition).naive_local(),
+            gap_end: gap_end.naive_local(),
+        })
+    }
+}
+
+/// Returns the naive local datetime `naive` at the fixed offset `offset`.
+fn in_offset(naive: NaiveDateTime, offset: FixedOffset) -> DateTime<FixedOffset> {
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .unwrap_or_else(|| offset.from_utc_datetime(&naive))
+}
+
+/// Returns the naive local datetime `naive` at `offset`, an offset given in the input.
+fn with_offset(
+    naive: NaiveDateTime,
+    offset: FixedOffset,
+) -> Result<DateTime<FixedOffset>, TimeParseError> {
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .ok_or(TimeParseError::DatetimeOutOfRange {
+            datetime: naive,
+            offset,
+        })
+}
+
+/// Builder for [`Utcizer`].
+///
+/// Every setting is optional. By default the fallback timezone is UTC, dates are read
+/// month-first (US style), no custom formats are configured, and ambiguous or nonexistent
+/// local times are reported as errors.
+#[derive(Debug, Clone)]
+pub struct UtcizerBuilder {
+    fallback_tz: TimeZoneParsed,
+    date_order: DateOrder,
+    custom_formats: Vec<String>,
+    ambiguity_policy: AmbiguityPolicy,
+    gap_policy: GapPolicy,
+    epoch: EpochOption,
+    epoch_window: EpochWindow,
+    serial_dates: Option<SerialDateSystem>,
+    syslog: Option<SyslogYear>,
+    abbreviation_preference: Vec<String>,
+    js_minute_offsets: bool,
+    locales: Vec<Locale>,
+    timezone_provider: Arc<dyn TimeZoneProvider>,
+}
+
+impl Default for UtcizerBuilder {
+    fn default() -> Self {
+        Self {
+            fallback_tz: TimeZoneParsed::FixedOffset(FixedOffset::east_opt(0).unwrap()),
+            date_order: DateOrder::default(),
+            custom_formats: Vec::new(),
+            ambiguity_policy: AmbiguityPolicy::default(),
+            gap_policy: GapPolicy::default(),
+            epoch: EpochOption::default(),
+            epoch_window: EpochWindow::default(),
+            serial_dates: None,
+            syslog: None,
+            abbreviation_preference: Vec::new(),
+            js_minute_offsets: false,
+            locales: Locale::ALL.to_vec(),
+            timezone_provider: Arc::new(BundledProvider),
+        }
+    }
+}
+
+impl UtcizerBuilder {
+    /// Sets the timezone used when the input has no timezone information.
+    ///
+    /// Use [`parse_timezone_str`](crate::tz::parse_timezone_str) to obtain a
+    /// [`TimeZoneParsed`] from a string such as `Asia/Jakarta` or `+07:00`.
+    pub fn fallback_tz(mut self, tz: TimeZoneParsed) -> Self {
+        self.fallback_tz = tz;
+        self
+    }
+
+    /// Sets the preferred order of day and month for numeric dates such as `01-06-2023`.
+    pub fn date_order(mut self, order: DateOrder) -> Self {
+        self.date_order = order;
+        self
+    }
+
+    /// Sets the custom formats tried before the default formats, replacing any set earlier.
+    pub fn custom_formats<I, S>(mut self, formats: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.custom_formats = formats
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Sets how a local time that occurs twice in the fallback timezone is resolved.
+    ///
+    /// Defaults to [`AmbiguityPolicy::Error`].
+    pub fn ambiguity_policy(mut self, policy: AmbiguityPolicy) -> Self {
+        self.ambiguity_policy = policy;
+        self
+    }
+
+    /// Sets how a local time that falls into a gap of the fallback timezone is resolved.
+    ///
+    /// Defaults to [`GapPolicy::Error`].
+    pub fn gap_policy(mut self, policy: GapPolicy) -> Self {
+        self.gap_policy = policy;
+        self
+    }
+
+    /// Sets how numeric inputs are interpreted as epochs.
+    ///
+    /// Use [`EpochOption::Fixed`] or [`EpochOption::Custom`] to force a unit when values are
+    /// too short or too long for detection, or [`EpochOption::Family`] for non-Unix timestamps
+    /// such as .NET ticks or Windows `FILETIME`. Defaults to [`EpochOption::Auto`].
+    pub fn epoch(mut self, epoch: EpochOption) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Sets the window numeric inputs must fall into to be detected as epochs.
+    ///
+    /// Numeric inputs outside the window are tried against the formats instead.
+    /// Only used with [`EpochOption::Auto`].
+    /// Defaults to [`EpochWindow::default`].
+    pub fn epoch_window(mut self, window: EpochWindow) -> Self {
+        self.epoch_window = window;
+        self
+    }
+
+    /// Enables spreadsheet serial dates such as `45078.4166667` in the given date system.
+    ///
+    /// When enabled, unsigned numbers up to `2958465` (9999-12-31), with or without a fraction,
+    /// are read as serial dates instead of epochs. Serial dates are local wall time and are
+    /// resolved in the fallback timezone. Disabled by default.
+    pub fn serial_dates(mut self, system: SerialDateSystem) -> Self {
+        self.serial_dates = Some(system);
+        self
+    }
+
+    /// Enables syslog mode: RFC 5424 timestamps such as `2023-06-01T10:00:00.123456+07:00` and
+    /// RFC 3164 timestamps such as `Jun  1 10:00:00`, with a space-padded or zero-padded day.
+    ///
+    /// RFC 3164 timestamps are local time of the sender and are resolved in the fallback
+    /// timezone, in the year chosen by `year`. The RFC 5424 NILVALUE `-` is reported as
+    /// [`TimeParseError::NilTimestamp`]. Inputs of neither shape are parsed as usual.
+    /// Disabled by default.
+    pub fn syslog(mut self, year: SyslogYear) -> Self {
+        self.syslog = Some(year);
+        self
+    }
+
+    /// Sets the countries used to resolve ambiguous timezone abbreviations, most preferred first.
+    ///
+    /// Countries are ISO 3166 codes such as `IN` or `US`; for example `["IN"]` reads `IST` as
+    /// India Standard Time rather than Irish or Israel Standard Time. Without a matching
+    /// preference, ambiguous abbreviations are reported as
+    /// [`TimeParseError::AmbiguousAbbreviation`]. Empty by default.
+    pub fn abbreviation_preference<I, S>(mut self, countries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.abbreviation_preference = countries
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Reads signed integer offset suffixes such as `-420` as JavaScript `getTimezoneOffset()`
+    /// values: minutes *behind* UTC, so `2023-06-01 10:00:00 -420` is at UTC+07:00.
+    ///
+    /// Disabled by default, since `-420` otherwise reads as `-04:20`. Zero-padded offsets such
+    /// as `+07` or `+0700` keep their usual meaning either way.
+    pub fn js_minute_offsets(mut self, enabled: bool) -> Self {
+        self.js_minute_offsets = enabled;
+        self
+    }
+
+    /// Sets the languages whose month and weekday names are recognised, such as `Juni` or
+    /// `mercredi`, replacing any set earlier.
+    ///
+    /// Names are matched ignoring case and diacritics, in both the default and the custom
+    /// formats. English names always take precedence, then the locales in the given order;
+    /// an empty list recognises English names only. Defaults to [`Locale::ALL`].
+    pub fn locales<I>(mut self, locales: I) -> Self
+    where
+        I: IntoIterator<Item = Locale>,
+    {
+        self.locales = locales.into_iter().collect();
+        self
+    }
+
+    /// Sets the provider used to look up IANA timezones named in the input, such as
+    /// `2023-06-01 10:00:00 Asia/Jakarta`.
+    ///
+    /// The fallback timezone is looked up separately, e.g. through
+    /// [`TimezoneOptions::provider`](crate::tz::TimezoneOptions::provider). Defaults to
+    /// [`BundledProvider`].
+    pub fn timezone_provider(mut self, provider: Arc<dyn TimeZoneProvider>) -> Self {
+        self.timezone_provider = provider;
+        self
+    }
+
+    /// Builds the [`Utcizer`], compiling the custom formats once.
+    ///
+    /// Custom formats chrono cannot understand are skipped, since they could never match.
+    pub fn build(self) -> Utcizer {
+        Utcizer {
+            fallback_tz: self.fallback_tz,
+            date_order: self.date_order,
+            custom_formats: CompiledFormats::new(&self.custom_formats),
+            ambiguity_policy: self.ambiguity_policy,
+            gap_policy: self.gap_policy,
+            epoch: self.epoch,
+            epoch_window: self.epoch_window,
+            serial_dates: self.serial_dates,
+            syslog: self.syslog,
+            abbreviation_preference: self.abbreviation_preference,
+            js_minute_offsets: self.js_minute_offsets,
+            locales: self.locales,
+            timezone_provider: self.timezone_provider,
+        }
+    }
+}
@@ -0,0 +1,532 @@
+use chrono_tz::Tz;
+
+/// Windows time zone names mapped to IANA timezones, after the CLDR `windowsZones` table.
+///
+/// Each entry is `(windows name, territory, IANA zones)`. Territory `001` holds the default
+/// zone for the Windows name; other territories are ISO 3166 country codes listing the zones
+/// used in that country, most representative first. Zones use their canonical IANA names.
+static WINDOWS_ZONES: &[(&str, &str, &str)] = &[
+    ("Dateline Standard Time", "001", "Etc/GMT+12"),
+    ("UTC-11", "001", "Etc/GMT+11"),
+    ("Aleutian Standard Time", "001", "America/Adak"),
+    ("Hawaiian Standard Time", "001", "Pacific/Honolulu"),
+    ("Marquesas Standard Time", "001", "Pacific/Marquesas"),
+    ("Alaskan Standard Time", "001", "America/Anchorage"),
+    ("UTC-09", "001", "Etc/GMT+9"),
+    ("Pacific Standard Time (Mexico)", "001", "America/Tijuana"),
+    ("UTC-08", "001", "Etc/GMT+8"),
+    ("Pacific Standard Time", "001", "America/Los_Angeles"),
+    ("US Mountain Standard Time", "001", "America/Phoenix"),
+    ("Mountain Standard Time (Mexico)", "001", "America/Mazatlan"),
+    ("Mountain Standard Time", "001", "America/Denver"),
+    ("Yukon Standard Time", "001", "America/Whitehorse"),
+    ("Central America Standard Time", "001", "America/Guatemala"),
+    ("Central Standard Time", "001", "America/Chicago"),
+    ("Easter Island Standard Time", "001", "Pacific/Easter"),
+    (
+        "Central Standard Time (Mexico)",
+        "001",
+        "America/Mexico_City",
+    ),
+    ("Canada Central Standard Time", "001", "America/Regina"),
+    ("SA Pacific Standard Time", "001", "America/Bogota"),
+    ("Eastern Standard Time (Mexico)", "001", "America/Cancun"),
+    ("Eastern Standard Time", "001", "America/New_York"),
+    ("Haiti Standard Time", "001", "America/Port-au-Prince"),
+    ("Cuba Standard Time", "001", "America/Havana"),
+    (
+        "US Eastern Standard Time",
+        "001",
+        "America/Indiana/Indianapolis",
+    ),
+    (
+        "Turks And Caicos Standard Time",
+        "001",
+        "America/Grand_Turk",
+    ),
+    ("Paraguay Standard Time", "001", "America/Asuncion"),
+    ("Atlantic Standard Time", "001", "America/Halifax"),
+    ("Venezuela Standard Time", "001", "America/Caracas"),
+    ("Central Brazilian Standard Time", "001", "America/Cuiaba"),
+    ("SA Western Standard Time", "001", "America/La_Paz"),
+    ("Pacific SA Standard Time", "001", "America/Santiago"),
+    ("Newfoundland Standard Time", "001", "America/St_Johns"),
+    ("Tocantins Standard Time", "001", "America/Araguaina"),
+    ("E. South America Standard Time", "001", "America/Sao_Paulo"),
+    ("SA Eastern Standard Time", "001", "America/Cayenne"),
+    (
+        "Argentina Standard Time",
+        "001",
+        "America/Argentina/Buenos_Aires",
+    ),
+    ("Greenland Standard Time", "001", "America/Nuuk"),
+    ("Montevideo Standard Time", "001", "America/Montevideo"),
+    ("Magallanes Standard Time", "001", "America/Punta_Arenas"),
+    ("Saint Pierre Standard Time", "001", "America/Miquelon"),
+    ("Bahia Standard Time", "001", "America/Bahia"),
+    ("UTC-02", "001", "Etc/GMT+2"),
+    ("Azores Standard Time", "001", "Atlantic/Azores"),
+    ("Cape Verde Standard Time", "001", "Atlantic/Cape_Verde"),
+    ("UTC", "001", "Etc/UTC"),
+    ("GMT Standard Time", "001", "Europe/London"),
+    ("Greenwich Standard Time", "001", "Atlantic/Reykjavik"),
+    ("Sao Tome Standard Time", "001", "Africa/Sao_Tome"),
+    ("Morocco Standard Time", "001", "Africa/Casablanca"),
+    ("W. Europe Standard Time", "001", "Europe/Berlin"),
+    ("Central Europe Standard Time", "001", "Europe/Budapest"),
+    ("Romance Standard Time", "001", "Europe/Paris"),
+    ("Central European Standard Time", "001", "Europe/Warsaw"),
+    ("W. Central Africa Standard Time", "001", "Africa/Lagos"),
+    ("Jordan Standard Time", "001", "Asia/Amman"),
+    ("GTB Standard Time", "001", "Europe/Bucharest"),
+    ("Middle East Standard Time", "001", "Asia/Beirut"),
+    ("Egypt Standard Time", "001", "Africa/Cairo"),
+    ("E. Europe Standard Time", "001", "Europe/Chisinau"),
+    ("Syria Standard Time", "001", "Asia/Damascus"),
+    ("West Bank Standard Time", "001", "Asia/Hebron"),
+    ("South Africa Standard Time", "001", "Africa/Johannesburg"),
+    ("FLE Standard Time", "001", "Europe/Kyiv"),
+    ("Israel Standard Time", "001", "Asia/Jerusalem"),
+    ("South Sudan Standard Time", "001", "Africa/Juba"),
+    ("Kaliningrad Standard Time", "001", "Europe/Kaliningrad"),
+    ("Sudan Standard Time", "001", "Africa/Khartoum"),
+    ("Libya Standard Time", "001", "Africa/Tripoli"),
+    ("Namibia Standard Time", "001", "Africa/Windhoek"),
+    ("Arabic Standard Time", "001", "Asia/Baghdad"),
+    ("Turkey Standard Time", "001", "Europe/Istanbul"),
+    ("Arab Standard Time", "001", "Asia/Riyadh"),
+    ("Belarus Standard Time", "001", "Europe/Minsk"),
+    ("Russian Standard Time", "001", "Europe/Moscow"),
+    ("E. Africa Standard Time", "001", "Africa/Nairobi"),
+    ("Volgograd Standard Time", "001", "Europe/Volgograd"),
+    ("Iran Standard Time", "001", "Asia/Tehran"),
+    ("Arabian Standard Time", "001", "Asia/Dubai"),
+    ("Astrakhan Standard Time", "001", "Europe/Astrakhan"),
+    ("Azerbaijan Standard Time", "001", "Asia/Baku"),
+    ("Russia Time Zone 3", "001", "Europe/Samara"),
+    ("Mauritius Standard Time", "001", "Indian/Mauritius"),
+    ("Saratov Standard Time", "001", "Europe/Saratov"),
+    ("Georgian Standard Time", "001", "Asia/Tbilisi"),
+    ("Caucasus Standard Time", "001", "Asia/Yerevan"),
+    ("Afghanistan Standard Time", "001", "Asia/Kabul"),
+    ("West Asia Standard Time", "001", "Asia/Tashkent"),
+    ("Ekaterinburg Standard Time", "001", "Asia/Yekaterinburg"),
+    ("Pakistan Standard Time", "001", "Asia/Karachi"),
+    ("Qyzylorda Standard Time", "001", "Asia/Qyzylorda"),
+    ("India Standard Time", "001", "Asia/Kolkata"),
+    ("Sri Lanka Standard Time", "001", "Asia/Colombo"),
+    ("Nepal Standard Time", "001", "Asia/Kathmandu"),
+    ("Central Asia Standard Time", "001", "Asia/Bishkek"),
+    ("Bangladesh Standard Time", "001", "Asia/Dhaka"),
+    ("Omsk Standard Time", "001", "Asia/Omsk"),
+    ("Myanmar Standard Time", "001", "Asia/Yangon"),
+    ("SE Asia Standard Time", "001", "Asia/Bangkok"),
+    ("Altai Standard Time", "001", "Asia/Barnaul"),
+    ("W. Mongolia Standard Time", "001", "Asia/Hovd"),
+    ("North Asia Standard Time", "001", "Asia/Krasnoyarsk"),
+    ("N. Central Asia Standard Time", "001", "Asia/Novosibirsk"),
+    ("Tomsk Standard Time", "001", "Asia/Tomsk"),
+    ("China Standard Time", "001", "Asia/Shanghai"),
+    ("North Asia East Standard Time", "001", "Asia/Irkutsk"),
+    ("Singapore Standard Time", "001", "Asia/Singapore"),
+    ("W. Australia Standard Time", "001", "Australia/Perth"),
+    ("Taipei Standard Time", "001", "Asia/Taipei"),
+    ("Ulaanbaatar Standard Time", "001", "Asia/Ulaanbaatar"),
+    ("Aus Central W. Standard Time", "001", "Australia/Eucla"),
+    ("Transbaikal Standard Time", "001", "Asia/Chita"),
+    ("Tokyo Standard Time", "001", "Asia/Tokyo"),
+    ("North Korea Standard Time", "001", "Asia/Pyongyang"),
+    ("Korea Standard Time", "001", "Asia/Seoul"),
+    ("Yakutsk Standard Time", "001", "Asia/Yakutsk"),
+    ("Cen. Australia Standard Time", "001", "Australia/Adelaide"),
+    ("AUS Central Standard Time", "001", "Australia/Darwin"),
+    ("E. Australia Standard Time", "001", "Australia/Brisbane"),
+    ("AUS Eastern Standard Time", "001", "Australia/Sydney"),
+    ("West Pacific Standard Time", "001", "Pacific/Port_Moresby"),
+    ("Tasmania Standard Time", "001", "Australia/Hobart"),
+    ("Vladivostok Standard Time", "001", "Asia/Vladivostok"),
+    ("Lord Howe Standard Time", "001", "Australia/Lord_Howe"),
+    ("Bougainville Standard Time", "001", "Pacific/Bougainville"),
+    ("Russia Time Zone 10", "001", "Asia/Srednekolymsk"),
+    ("Magadan Standard Time", "001", "Asia/Magadan"),
+    ("Norfolk Standard Time", "001", "Pacific/Norfolk"),
+    ("Sakhalin Standard Time", "001", "Asia/Sakhalin"),
+    (
+        "Central Pacific Standard Time",
+        "001",
+        "Pacific/Guadalcanal",
+    ),
+    ("Russia Time Zone 11", "001", "Asia/Kamchatka"),
+    ("New Zealand Standard Time", "001", "Pacific/Auckland"),
+    ("UTC+12", "001", "Etc/GMT-12"),
+    ("Fiji Standard Time", "001", "Pacific/Fiji"),
+    ("Chatham Islands Standard Time", "001", "Pacific/Chatham"),
+    ("UTC+13", "001", "Etc/GMT-13"),
+    ("Tonga Standard Time", "001", "Pacific/Tongatapu"),
+    ("Samoa Standard Time", "001", "Pacific/Apia"),
+    ("Line Islands Standard Time", "001", "Pacific/Kiritimati"),
+    ("Hawaiian Standard Time", "US", "Pacific/Honolulu"),
+    ("Hawaiian Standard Time", "CK", "Pacific/Rarotonga"),
+    ("Hawaiian Standard Time", "PF", "Pacific/Tahiti"),
+    (
+        "Alaskan Standard Time",
+        "US",
+        "America/Anchorage America/Juneau America/Metlakatla America/Nome America/Sitka America/Yakutat",
+    ),
+    ("Pacific Standard Time", "US", "America/Los_Angeles"),
+    ("Pacific Standard Time", "CA", "America/Vancouver"),
+    ("US Mountain Standard Time", "US", "America/Phoenix"),
+    (
+        "US Mountain Standard Time",
+        "CA",
+        "America/Creston America/Dawson_Creek America/Fort_Nelson",
+    ),
+    ("US Mountain Standard Time", "MX", "America/Hermosillo"),
+    (
+        "Mountain Standard Time",
+        "US",
+        "America/Denver America/Boise",
+    ),
+    (
+        "Mountain Standard Time",
+        "CA",
+        "America/Edmonton America/Cambridge_Bay America/Inuvik",
+    ),
+    ("Mountain Standard Time", "MX", "America/Ciudad_Juarez"),
+    ("Central America Standard Time", "GT", "America/Guatemala"),
+    ("Central America Standard Time", "BZ", "America/Belize"),
+    ("Central America Standard Time", "CR", "America/Costa_Rica"),
+    ("Central America Standard Time", "SV", "America/El_Salvador"),
+    ("Central America Standard Time", "HN", "America/Tegucigalpa"),
+    ("Central America Standard Time", "NI", "America/Managua"),
+    (
+        "Central Standard Time",
+        "US",
+        "America/Chicago America/Indiana/Knox America/Indiana/Tell_City America/Menominee America/North_Dakota/Beulah America/North_Dakota/Center America/North_Dakota/New_Salem",
+    ),
+    (
+        "Central Standard Time",
+        "CA",
+        "America/Winnipeg America/Rankin_Inlet America/Resolute",
+    ),
+    (
+        "Central Standard Time",
+        "MX",
+        "America/Matamoros America/Ojinaga",
+    ),
+    (
+        "Central Standard Time (Mexico)",
+        "MX",
+        "America/Mexico_City America/Bahia_Banderas America/Merida America/Monterrey America/Chihuahua",
+    ),
+    (
+        "Canada Central Standard Time",
+        "CA",
+        "America/Regina America/Swift_Current",
+    ),
+    ("SA Pacific Standard Time", "CO", "America/Bogota"),
+    ("SA Pacific Standard Time", "PE", "America/Lima"),
+    ("SA Pacific Standard Time", "EC", "America/Guayaquil"),
+    ("SA Pacific Standard Time", "PA", "America/Panama"),
+    ("SA Pacific Standard Time", "JM", "America/Jamaica"),
+    (
+        "SA Pacific Standard Time",
+        "BR",
+        "America/Rio_Branco America/Eirunepe",
+    ),
+    ("SA Pacific Standard Time", "CA", "America/Coral_Harbour"),
+    ("SA Pacific Standard Time", "KY", "America/Cayman"),
+    (
+        "Eastern Standard Time",
+        "US",
+        "America/New_York America/Detroit America/Indiana/Petersburg America/Indiana/Vincennes America/Indiana/Winamac America/Kentucky/Monticello America/Kentucky/Louisville",
+    ),
+    (
+        "Eastern Standard Time",
+        "CA",
+        "America/Toronto America/Iqaluit",
+    ),
+    ("Eastern Standard Time", "BS", "America/Nassau"),
+    (
+        "US Eastern Standard Time",
+        "US",
+        "America/Indiana/Indianapolis America/Indiana/Marengo America/Indiana/Vevay",
+    ),
+    (
+        "Atlantic Standard Time",
+        "CA",
+        "America/Halifax America/Glace_Bay America/Goose_Bay America/Moncton",
+    ),
+    ("Atlantic Standard Time", "BM", "Atlantic/Bermuda"),
+    ("Atlantic Standard Time", "GL", "America/Thule"),
+    ("SA Western Standard Time", "BO", "America/La_Paz"),
+    (
+        "SA Western Standard Time",
+        "BR",
+        "America/Manaus America/Boa_Vista America/Porto_Velho",
+    ),
+    ("SA Western Standard Time", "PR", "America/Puerto_Rico"),
+    ("SA Western Standard Time", "DO", "America/Santo_Domingo"),
+    ("SA Western Standard Time", "TT", "America/Port_of_Spain"),
+    ("SA Western Standard Time", "GY", "America/Guyana"),
+    ("Newfoundland Standard Time", "CA", "America/St_Johns"),
+    ("E. South America Standard Time", "BR", "America/Sao_Paulo"),
+    ("SA Eastern Standard Time", "GF", "America/Cayenne"),
+    (
+        "SA Eastern Standard Time",
+        "BR",
+        "America/Fortaleza America/Belem America/Maceio America/Recife America/Santarem",
+    ),
+    ("SA Eastern Standard Time", "SR", "America/Paramaribo"),
+    (
+        "Argentina Standard Time",
+        "AR",
+        "America/Argentina/Buenos_Aires America/Argentina/La_Rioja America/Argentina/Rio_Gallegos America/Argentina/Salta America/Argentina/San_Juan America/Argentina/San_Luis America/Argentina/Tucuman America/Argentina/Ushuaia America/Argentina/Catamarca America/Argentina/Cordoba America/Argentina/Jujuy America/Argentina/Mendoza",
+    ),
+    ("Pacific SA Standard Time", "CL", "America/Santiago"),
+    ("GMT Standard Time", "GB", "Europe/London"),
+    ("GMT Standard Time", "IE", "Europe/Dublin"),
+    ("GMT Standard Time", "PT", "Europe/Lisbon Atlantic/Madeira"),
+    ("GMT Standard Time", "ES", "Atlantic/Canary"),
+    ("GMT Standard Time", "FO", "Atlantic/Faroe"),
+    ("GMT Standard Time", "GG", "Europe/Guernsey"),
+    ("GMT Standard Time", "IM", "Europe/Isle_of_Man"),
+    ("GMT Standard Time", "JE", "Europe/Jersey"),
+    ("Greenwich Standard Time", "IS", "Atlantic/Reykjavik"),
+    ("Greenwich Standard Time", "GH", "Africa/Accra"),
+    ("Greenwich Standard Time", "CI", "Africa/Abidjan"),
+    ("Greenwich Standard Time", "SN", "Africa/Dakar"),
+    ("Greenwich Standard Time", "ML", "Africa/Bamako"),
+    ("Morocco Standard Time", "MA", "Africa/Casablanca"),
+    (
+        "W. Europe Standard Time",
+        "DE",
+        "Europe/Berlin Europe/Busingen",
+    ),
+    ("W. Europe Standard Time", "AT", "Europe/Vienna"),
+    ("W. Europe Standard Time", "CH", "Europe/Zurich"),
+    ("W. Europe Standard Time", "IT", "Europe/Rome"),
+    ("W. Europe Standard Time", "NL", "Europe/Amsterdam"),
+    ("W. Europe Standard Time", "SE", "Europe/Stockholm"),
+    ("W. Europe Standard Time", "NO", "Europe/Oslo"),
+    ("W. Europe Standard Time", "LU", "Europe/Luxembourg"),
+    ("W. Europe Standard Time", "MT", "Europe/Malta"),
+    ("W. Europe Standard Time", "MC", "Europe/Monaco"),
+    ("W. Europe Standard Time", "LI", "Europe/Vaduz"),
+    ("W. Europe Standard Time", "SM", "Europe/San_Marino"),
+    ("W. Europe Standard Time", "VA", "Europe/Vatican"),
+    ("W. Europe Standard Time", "AD", "Europe/Andorra"),
+    ("W. Europe Standard Time", "GI", "Europe/Gibraltar"),
+    ("W. Europe Standard Time", "SJ", "Arctic/Longyearbyen"),
+    ("Central Europe Standard Time", "HU", "Europe/Budapest"),
+    ("Central Europe Standard Time", "CZ", "Europe/Prague"),
+    ("Central Europe Standard Time", "SK", "Europe/Bratislava"),
+    ("Central Europe Standard Time", "SI", "Europe/Ljubljana"),
+    ("Central Europe Standard Time", "RS", "Europe/Belgrade"),
+    ("Central Europe Standard Time", "ME", "Europe/Podgorica"),
+    ("Central Europe Standard Time", "AL", "Europe/Tirane"),
+    ("Romance Standard Time", "FR", "Europe/Paris"),
+    ("Romance Standard Time", "BE", "Europe/Brussels"),
+    ("Romance Standard Time", "DK", "Europe/Copenhagen"),
+    ("Romance Standard Time", "ES", "Europe/Madrid Africa/Ceuta"),
+    ("Central European Standard Time", "PL", "Europe/Warsaw"),
+    ("Central European Standard Time", "HR", "Europe/Zagreb"),
+    ("Central European Standard Time", "BA", "Europe/Sarajevo"),
+    ("Central European Standard Time", "MK", "Europe/Skopje"),
+    ("W. Central Africa Standard Time", "NG", "Africa/Lagos"),
+    ("W. Central Africa Standard Time", "DZ", "Africa/Algiers"),
+    ("W. Central Africa Standard Time", "TN", "Africa/Tunis"),
+    ("W. Central Africa Standard Time", "CM", "Africa/Douala"),
+    ("W. Central Africa Standard Time", "AO", "Africa/Luanda"),
+    ("W. Central Africa Standard Time", "CD", "Africa/Kinshasa"),
+    ("GTB Standard Time", "RO", "Europe/Bucharest"),
+    ("GTB Standard Time", "GR", "Europe/Athens"),
+    ("GTB Standard Time", "CY", "Asia/Nicosia Asia/Famagusta"),
+    ("Middle East Standard Time", "LB", "Asia/Beirut"),
+    ("Egypt Standard Time", "EG", "Africa/Cairo"),
+    ("E. Europe Standard Time", "MD", "Europe/Chisinau"),
+    ("South Africa Standard Time", "ZA", "Africa/Johannesburg"),
+    ("South Africa Standard Time", "ZW", "Africa/Harare"),
+    ("South Africa Standard Time", "ZM", "Africa/Lusaka"),
+    ("South Africa Standard Time", "MZ", "Africa/Maputo"),
+    ("South Africa Standard Time", "BW", "Africa/Gaborone"),
+    ("South Africa Standard Time", "MW", "Africa/Blantyre"),
+    ("South Africa Standard Time", "RW", "Africa/Kigali"),
+    ("South Africa Standard Time", "BI", "Africa/Bujumbura"),
+    ("South Africa Standard Time", "LS", "Africa/Maseru"),
+    ("South Africa Standard Time", "SZ", "Africa/Mbabane"),
+    ("FLE Standard Time", "UA", "Europe/Kyiv"),
+    ("FLE Standard Time", "FI", "Europe/Helsinki"),
+    ("FLE Standard Time", "EE", "Europe/Tallinn"),
+    ("FLE Standard Time", "LV", "Europe/Riga"),
+    ("FLE Standard Time", "LT", "Europe/Vilnius"),
+    ("FLE Standard Time", "BG", "Europe/Sofia"),
+    ("FLE Standard Time", "AX", "Europe/Mariehamn"),
+    ("Israel Standard Time", "IL", "Asia/Jerusalem"),
+    ("Arabic Standard Time", "IQ", "Asia/Baghdad"),
+    ("Turkey Standard Time", "TR", "Europe/Istanbul"),
+    ("Arab Standard Time", "SA", "Asia/Riyadh"),
+    ("Arab Standard Time", "KW", "Asia/Kuwait"),
+    ("Arab Standard Time", "QA", "Asia/Qatar"),
+    ("Arab Standard Time", "BH", "Asia/Bahrain"),
+    ("Arab Standard Time", "YE", "Asia/Aden"),
+    ("Belarus Standard Time", "BY", "Europe/Minsk"),
+    ("Russian Standard Time", "RU", "Europe/Moscow Europe/Kirov"),
+    ("Russian Standard Time", "UA", "Europe/Simferopol"),
+    ("E. Africa Standard Time", "KE", "Africa/Nairobi"),
+    ("E. Africa Standard Time", "ET", "Africa/Addis_Ababa"),
+    ("E. Africa Standard Time", "TZ", "Africa/Dar_es_Salaam"),
+    ("E. Africa Standard Time", "UG", "Africa/Kampala"),
+    ("E. Africa Standard Time", "SO", "Africa/Mogadishu"),
+    ("E. Africa Standard Time", "ER", "Africa/Asmara"),
+    ("E. Africa Standard Time", "DJ", "Africa/Djibouti"),
+    ("E. Africa Standard Time", "MG", "Indian/Antananarivo"),
+    ("Iran Standard Time", "IR", "Asia/Tehran"),
+    ("Arabian Standard Time", "AE", "Asia/Dubai"),
+    ("Arabian Standard Time", "OM", "Asia/Muscat"),
+    ("Azerbaijan Standard Time", "AZ", "Asia/Baku"),
+    ("Georgian Standard Time", "GE", "Asia/Tbilisi"),
+    ("Caucasus Standard Time", "AM", "Asia/Yerevan"),
+    ("Afghanistan Standard Time", "AF", "Asia/Kabul"),
+    (
+        "West Asia Standard Time",
+        "UZ",
+        "Asia/Tashkent Asia/Samarkand",
+    ),
+    ("West Asia Standard Time", "TJ", "Asia/Dushanbe"),
+    ("West Asia Standard Time", "TM", "Asia/Ashgabat"),
+    (
+        "West Asia Standard Time",
+        "KZ",
+        "Asia/Oral Asia/Aqtau Asia/Aqtobe Asia/Atyrau",
+    ),
+    ("West Asia Standard Time", "MV", "Indian/Maldives"),
+    ("Ekaterinburg Standard Time", "RU", "Asia/Yekaterinburg"),
+    ("Pakistan Standard Time", "PK", "Asia/Karachi"),
+    ("India Standard Time", "IN", "Asia/Kolkata"),
+    ("Sri Lanka Standard Time", "LK", "Asia/Colombo"),
+    ("Nepal Standard Time", "NP", "Asia/Kathmandu"),
+    ("Central Asia Standard Time", "KG", "Asia/Bishkek"),
+    (
+        "Central Asia Standard Time",
+        "KZ",
+        "Asia/Almaty Asia/Qostanay",
+    ),
+    ("Bangladesh Standard Time", "BD", "Asia/Dhaka"),
+    ("Bangladesh Standard Time", "BT", "Asia/Thimphu"),
+    ("Myanmar Standard Time", "MM", "Asia/Yangon"),
+    ("SE Asia Standard Time", "TH", "Asia/Bangkok"),
+    ("SE Asia Standard Time", "ID", "Asia/Jakarta Asia/Pontianak"),
+    ("SE Asia Standard Time", "VN", "Asia/Ho_Chi_Minh"),
+    ("SE Asia Standard Time", "KH", "Asia/Phnom_Penh"),
+    ("SE Asia Standard Time", "LA", "Asia/Vientiane"),
+    ("SE Asia Standard Time", "CX", "Indian/Christmas"),
+    (
+        "North Asia Standard Time",
+        "RU",
+        "Asia/Krasnoyarsk Asia/Novokuznetsk",
+    ),
+    ("China Standard Time", "CN", "Asia/Shanghai"),
+    ("China Standard Time", "HK", "Asia/Hong_Kong"),
+    ("China Standard Time", "MO", "Asia/Macau"),
+    ("North Asia East Standard Time", "RU", "Asia/Irkutsk"),
+    ("Singapore Standard Time", "SG", "Asia/Singapore"),
+    (
+        "Singapore Standard Time",
+        "MY",
+        "Asia/Kuala_Lumpur Asia/Kuching",
+    ),
+    ("Singapore Standard Time", "PH", "Asia/Manila"),
+    ("Singapore Standard Time", "ID", "Asia/Makassar"),
+    ("Singapore Standard Time", "BN", "Asia/Brunei"),
+    ("W. Australia Standard Time", "AU", "Australia/Perth"),
+    ("Taipei Standard Time", "TW", "Asia/Taipei"),
+    ("Ulaanbaatar Standard Time", "MN", "Asia/Ulaanbaatar"),
+    ("Tokyo Standard Time", "JP", "Asia/Tokyo"),
+    ("Tokyo Standard Time", "ID", "Asia/Jayapura"),
+    ("Tokyo Standard Time", "TL", "Asia/Dili"),
+    ("Tokyo Standard Time", "PW", "Pacific/Palau"),
+    ("North Korea Standard Time", "KP", "Asia/Pyongyang"),
+    ("Korea Standard Time", "KR", "Asia/Seoul"),
+    ("Yakutsk Standard Time", "RU", "Asia/Yakutsk Asia/Khandyga"),
+    (
+        "Cen. Australia Standard Time",
+        "AU",
+        "Australia/Adelaide Australia/Broken_Hill",
+    ),
+    ("AUS Central Standard Time", "AU", "Australia/Darwin"),
+    (
+        "E. Australia Standard Time",
+        "AU",
+        "Australia/Brisbane Australia/Lindeman",
+    ),
+    (
+        "AUS Eastern Standard Time",
+        "AU",
+        "Australia/Sydney Australia/Melbourne",
+    ),
+    ("West Pacific Standard Time", "PG", "Pacific/Port_Moresby"),
+    ("West Pacific Standard Time", "GU", "Pacific/Guam"),
+    ("West Pacific Standard Time", "MP", "Pacific/Saipan"),
+    ("Tasmania Standard Time", "AU", "Australia/Hobart"),
+    (
+        "Vladivostok Standard Time",
+        "RU",
+        "Asia/Vladivostok Asia/Ust-Nera",
+    ),
+    ("Central Pacific Standard Time", "SB", "Pacific/Guadalcanal"),
+    ("Central Pacific Standard Time", "NC", "Pacific/Noumea"),
+    ("Central Pacific Standard Time", "VU", "Pacific/Efate"),
+    ("New Zealand Standard Time", "NZ", "Pacific/Auckland"),
+    ("New Zealand Standard Time", "AQ", "Antarctica/McMurdo"),
+    ("Fiji Standard Time", "FJ", "Pacific/Fiji"),
+    ("Tonga Standard Time", "TO", "Pacific/Tongatapu"),
+    ("Samoa Standard Time", "WS", "Pacific/Apia"),
+];
+
+/// Resolves a Windows time zone name such as `SE Asia Standard Time` to an IANA timezone.
+///
+/// If `territory` (an ISO 3166 country code) is given and the Windows zone is used there,
+/// the representative zone of that country is returned; otherwise the default zone is.
+/// Names are matched case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::windows::windows_to_iana;
+///
+/// assert_eq!(windows_to_iana("SE Asia Standard Time", None).unwrap().name(), "Asia/Bangkok");
+/// assert_eq!(windows_to_iana("SE Asia Standard Time", Some("ID")).unwrap().name(), "Asia/Jakarta");
+/// assert!(windows_to_iana("Mars Standard Time", None).is_none());
+/// ```
+pub fn windows_to_iana(name: &str, territory: Option<&str>) -> Option<Tz> {
+    let find = |territory: &str| {
+        WINDOWS_ZONES.iter().find(|(windows, t, _)| {
+            windows.eq_ignore_ascii_case(name) && t.eq_ignore_ascii_case(territory)
+        })
+    };
+
+    let (_, _, zones) = territory.and_then(find).or_else(|| find("001"))?;
+    zones.split(' ').next()?.parse().ok()
+}
+
+/// Returns the Windows time zone name for an IANA timezone, e.g. `W. Europe Standard Time`
+/// for `Europe/Berlin`.
+///
+/// Only canonical IANA names are known; links such as `Asia/Calcutta` are not.
+///
+/// # Examples
+///
+/// ```
+/// use utcize::windows::iana_to_windows;
+///
+/// assert_eq!(iana_to_windows("Asia/Jakarta"), Some("SE Asia Standard Time"));
+/// assert_eq!(iana_to_windows("Europe/Berlin"), Some("W. Europe Standard Time"));
+/// assert_eq!(iana_to_windows("Mars/Olympus"), None);
+/// ```
+pub fn iana_to_windows(iana: &str) -> Option<&'static str> {
+    WINDOWS_ZONES
+        .iter()
+        .find(|(_, _, zones)| zones.split(' ').any(|zone| zone == iana))
+        .map(|(windows, _, _)| *windows)
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use utcize::abbrev::resolve_abbreviation;
+    use utcize::datetime::{parse_datetime_flexible, utcize, utcize_detailed};
+    use utcize::error::TimeParseError;
+    use utcize::types::{ParseSource, ParsedDatetime, TimeZoneParsed};
+    use utcize::tz::{TimezoneOptions, parse_timezone_str, parse_timezone_str_with};
+    use utcize::utcizer::Utcizer;
+
+    fn rfc3339(s: &str) -> String {
+        utcize::<&str>(s, "UTC", false, None).unwrap().to_rfc3339()
+    }
+
+    #[test]
+    fn test_trailing_abbreviations() {
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00 WIB"),
+            "2023-06-01T03:00:00+00:00"
+        );
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00 WITA"),
+            "2023-06-01T02:00:00+00:00"
+        );
+        assert_eq!(rfc3339("Jun 1 2023 10:00 PDT"), "2023-06-01T17:00:00+00:00");
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00 CEST"),
+            "2023-06-01T08:00:00+00:00"
+        );
+        assert_eq!(
+            rfc3339("2023-01-15 10:00:00 EST"),
+            "2023-01-15T15:00:00+00:00"
+        );
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00 NST"),
+            "2023-06-01T13:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_compact_format_with_zone_name() {
+        let outcome =
+            utcize_detailed::<&str>("20230601100000JST", "Asia/Jakarta", false, None).unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T01:00:00+00:00");
+        assert_eq!(
+            outcome.source,
+            ParseSource::DefaultFormat("%Y%m%d%H%M%S".into())
+        );
+        assert!(!outcome.used_fallback);
+    }
+
+    #[test]
+    fn test_flexible_applies_abbreviation() {
+        match parse_datetime_flexible::<&str>("2023-06-01 10:00:00 WIB", false, None).unwrap() {
+            ParsedDatetime::WithTimezone(dt) => {
+                assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00")
+            }
+            other => panic!("Expected WithTimezone, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_abbreviation() {
+        match utcize::<&str>("2023-06-01 10:00:00 IST", "UTC", false, None).unwrap_err() {
+            TimeParseError::AmbiguousAbbreviation {
+                abbreviation,
+                candidates,
+            } => {
+                assert_eq!(abbreviation, "IST");
+                assert_eq!(candidates.len(), 3);
+                assert!(candidates.contains(&"Asia/Kolkata".to_string()));
+            }
+            e => panic!("Expected AmbiguousAbbreviation, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_region_preference() {
+        let utcizer = Utcizer::builder().abbreviation_preference(["IN"]).build();
+        let dt = utcizer.parse("2023-06-01 10:00:00 IST").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T04:30:00+00:00");
+
+        let utcizer = Utcizer::builder()
+            .abbreviation_preference(["de", "CN"])
+            .build();
+        let dt = utcizer.parse("2023-06-01 10:00:00 CST").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T02:00:00+00:00");
+
+        let us = resolve_abbreviation("CST", &["US"]).unwrap();
+        assert_eq!(us.offset_seconds, -6 * 3600);
+    }
+
+    #[test]
+    fn test_daylight_abbreviation_must_match_date() {
+        match utcize::<&str>("2023-01-15 10:00:00 PDT", "UTC", false, None).unwrap_err() {
+            TimeParseError::AbbreviationMismatch {
+                abbreviation, zone, ..
+            } => {
+                assert_eq!(abbreviation, "PDT");
+                assert_eq!(zone, "America/Los_Angeles");
+            }
+            e => panic!("Expected AbbreviationMismatch, got {e:?}"),
+        }
+
+        // Southern hemisphere daylight time falls in January.
+        assert_eq!(
+            rfc3339("2023-01-15 10:00:00 AEDT"),
+            "2023-01-14T23:00:00+00:00"
+        );
+        assert!(utcize::<&str>("2023-06-01 10:00:00 AEDT", "UTC", false, None).is_err());
+    }
+
+    #[test]
+    fn test_unknown_trailing_word_is_not_an_abbreviation() {
+        assert!(utcize::<&str>("2023-06-01 10:00:00 XYZ", "UTC", false, None).is_err());
+    }
+
+    #[test]
+    fn test_unknown_compact_abbreviation() {
+        match utcize::<&str>("20230601100000XYZ", "UTC", false, None).unwrap_err() {
+            TimeParseError::UnknownTimezone { name, .. } => assert_eq!(name, "XYZ"),
+            e => panic!("Expected UnknownTimezone, got {e:?}"),
+        }
+        assert!(utcize::<&str>("20230601T100000XYZ", "UTC", true, None).is_err());
+        assert_eq!(rfc3339("20230601T100000Z"), "2023-06-01T10:00:00+00:00");
+    }
+
+    #[test]
+    fn test_attached_meridiem_is_not_an_abbreviation() {
+        let custom = ["%m/%d/%Y %I:%M%p"];
+        let dt = utcize("06/01/2023 10:00PM", "UTC", false, Some(&custom)).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T22:00:00+00:00");
+    }
+
+    #[test]
+    fn test_month_name_abbreviations_in_both_date_orders() {
+        for prefer_eu in [true, false] {
+            let dt = utcize::<&str>("Jun 1 2023 10:00 PDT", "UTC", prefer_eu, None).unwrap();
+            assert_eq!(dt.to_rfc3339(), "2023-06-01T17:00:00+00:00");
+            let dt = utcize::<&str>("Jun 1 2023 10:00:00 WIB", "UTC", prefer_eu, None).unwrap();
+            assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        }
+    }
+
+    #[test]
+    fn test_timezone_str_abbreviations() {
+        match parse_timezone_str("WIB").unwrap() {
+            TimeZoneParsed::FixedOffset(offset) => assert_eq!(offset.local_minus_utc(), 7 * 3600),
+            other => panic!("Expected FixedOffset, got {other:?}"),
+        }
+
+        assert!(matches!(
+            parse_timezone_str("IST"),
+            Err(TimeParseError::AmbiguousAbbreviation { .. })
+        ));
+
+        let options = TimezoneOptions {
+            abbreviation_preference: vec!["IL".to_string()],
+            ..Default::default()
+        };
+        match parse_timezone_str_with("IST", &options).unwrap() {
+            TimeZoneParsed::FixedOffset(offset) => assert_eq!(offset.local_minus_utc(), 2 * 3600),
+            other => panic!("Expected FixedOffset, got {other:?}"),
+        }
+
+        let dt = utcize::<&str>("2023-06-01 10:00:00", "PDT", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T17:00:00+00:00");
+    }
+}
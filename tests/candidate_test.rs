@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use utcize::provider::TzifProvider;
+    use utcize::tz::{candidate_zones, candidate_zones_with};
+
+    fn names(dt: &str, hint: Option<&str>) -> Vec<String> {
+        let dt = DateTime::parse_from_rfc3339(dt).unwrap();
+        candidate_zones(&dt, hint)
+            .iter()
+            .map(|zone| zone.name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_ranked_by_population() {
+        let zones = names("2023-06-01T10:00:00+07:00", None);
+        assert_eq!(
+            zones[..3],
+            ["Asia/Jakarta", "Asia/Pontianak", "Asia/Novosibirsk"]
+        );
+        assert!(zones.contains(&"Asia/Bangkok".to_string()));
+        assert!(!zones.contains(&"Asia/Makassar".to_string()));
+
+        let zones = names("2023-01-15T10:00:00-05:00", None);
+        assert_eq!(zones[0], "America/New_York");
+        assert!(zones.contains(&"America/Bogota".to_string()));
+    }
+
+    #[test]
+    fn test_offset_at_the_instant() {
+        // Berlin is +01:00 in winter and +02:00 in summer.
+        assert!(names("2023-01-15T10:00:00+01:00", None).contains(&"Europe/Berlin".to_string()));
+        assert!(!names("2023-06-01T10:00:00+01:00", None).contains(&"Europe/Berlin".to_string()));
+        assert!(names("2023-06-01T10:00:00+02:00", None).contains(&"Europe/Berlin".to_string()));
+
+        // Only real zones are returned, never `Etc/GMT` zones.
+        assert!(names("2023-06-01T10:00:00+07:30", None).is_empty());
+    }
+
+    #[test]
+    fn test_country_hint() {
+        assert_eq!(
+            names("2023-06-01T10:00:00+07:00", Some("TH"))[0],
+            "Asia/Bangkok"
+        );
+        assert_eq!(
+            names("2023-01-15T10:00:00-05:00", Some("ca"))[0],
+            "America/Toronto"
+        );
+        // Zones shared by several countries match any of them.
+        assert_eq!(
+            names("2023-06-01T10:00:00+02:00", Some("DK"))[0],
+            "Europe/Berlin"
+        );
+        // A hint without a matching zone leaves the ranking unchanged.
+        assert_eq!(
+            names("2023-06-01T10:00:00+07:00", Some("JP")),
+            names("2023-06-01T10:00:00+07:00", None)
+        );
+    }
+
+    #[test]
+    fn test_candidates_from_provider() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/zoneinfo");
+        let provider = TzifProvider::new(dir).unwrap();
+        let dt = DateTime::parse_from_rfc3339("2023-06-01T10:00:00+07:00").unwrap();
+        let zones = candidate_zones_with(&dt, None, &provider);
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].name(), "Asia/Jakarta");
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use utcize::country::{country_zones, resolve_country};
+    use utcize::datetime::utcize;
+    use utcize::error::TimeParseError;
+    use utcize::types::TimeZoneParsed;
+    use utcize::tz::{TimezoneOptions, parse_timezone_str, parse_timezone_str_with};
+
+    fn iana_name(parsed: TimeZoneParsed) -> String {
+        match parsed {
+            TimeZoneParsed::Iana(tz) => tz.name().to_string(),
+            other => panic!("Expected Iana, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_single_zone_countries() {
+        assert_eq!(iana_name(parse_timezone_str("JP").unwrap()), "Asia/Tokyo");
+        assert_eq!(
+            iana_name(parse_timezone_str("sg").unwrap()),
+            "Asia/Singapore"
+        );
+        // Countries without a zone of their own resolve to the zone they share.
+        assert_eq!(
+            iana_name(parse_timezone_str("DK").unwrap()),
+            "Europe/Berlin"
+        );
+
+        let dt = utcize::<&str>("2023-06-01 10:00:00", "JP", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T01:00:00+00:00");
+    }
+
+    #[test]
+    fn test_multi_zone_countries() {
+        match parse_timezone_str("ID").unwrap_err() {
+            TimeParseError::AmbiguousCountry {
+                country,
+                candidates,
+            } => {
+                assert_eq!(country, "ID");
+                assert_eq!(
+                    candidates,
+                    [
+                        "Asia/Jakarta",
+                        "Asia/Pontianak",
+                        "Asia/Makassar",
+                        "Asia/Jayapura"
+                    ]
+                );
+            }
+            other => panic!("Expected AmbiguousCountry, got {other:?}"),
+        }
+
+        // Zones shared with a more populous country come last.
+        let de: Vec<_> = country_zones("DE").iter().map(|z| z.zone).collect();
+        assert_eq!(de, ["Europe/Berlin", "Europe/Zurich"]);
+        assert_eq!(country_zones("DE")[1].comment, "Büsingen");
+    }
+
+    #[test]
+    fn test_country_preference() {
+        let options = TimezoneOptions {
+            country_preference: vec!["America/Chicago".to_string(), "Asia/Makassar".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            iana_name(parse_timezone_str_with("ID", &options).unwrap()),
+            "Asia/Makassar"
+        );
+        assert_eq!(
+            iana_name(parse_timezone_str_with("US", &options).unwrap()),
+            "America/Chicago"
+        );
+        // Preferences for other countries are ignored.
+        assert!(matches!(
+            parse_timezone_str_with("AU", &options),
+            Err(TimeParseError::AmbiguousCountry { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_country() {
+        assert!(country_zones("XX").is_empty());
+        assert!(matches!(
+            resolve_country::<&str>("XX", &[]),
+            Err(TimeParseError::UnknownTimezone { .. })
+        ));
+    }
+
+    #[test]
+    fn test_every_zone_is_known() {
+        let mut countries = 0;
+        for a in 'A'..='Z' {
+            for b in 'A'..='Z' {
+                let zones = country_zones(&format!("{a}{b}"));
+                countries += usize::from(!zones.is_empty());
+                for zone in zones {
+                    assert!(zone.zone.parse::<chrono_tz::Tz>().is_ok(), "{}", zone.zone);
+                }
+            }
+        }
+        assert!(countries > 240, "{countries}");
+    }
+}
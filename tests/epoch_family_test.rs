@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use utcize::datetime::epoch_family_to_utc;
+    use utcize::error::TimeParseError;
+    use utcize::types::{EpochFamily, EpochOption, ParseSource};
+    use utcize::utcizer::Utcizer;
+
+    // Every value below is 2023-06-01T03:00:00Z in its family.
+    const CASES: [(EpochFamily, &str); 9] = [
+        (EpochFamily::DotNetTicks, "638211852000000000"),
+        (EpochFamily::WindowsFileTime, "133300620000000000"),
+        (EpochFamily::WebKit, "13330062000000000"),
+        (EpochFamily::Cocoa, "707281200"),
+        (EpochFamily::HfsPlus, "3768433200"),
+        (EpochFamily::Gps, "1369623618"),
+        (EpochFamily::Ntp, "3894577200"),
+        (EpochFamily::JulianDay, "2460096.625"),
+        (EpochFamily::ModifiedJulianDay, "60096.125"),
+    ];
+
+    #[test]
+    fn test_all_families() {
+        for (family, value) in CASES {
+            let dt = epoch_family_to_utc(value, family).unwrap().unwrap();
+            assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00", "{family:?}");
+        }
+    }
+
+    #[test]
+    fn test_fractions_and_signs() {
+        let dt = epoch_family_to_utc("707281200.25", EpochFamily::Cocoa)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00.250+00:00");
+
+        let dt = epoch_family_to_utc("-86400", EpochFamily::Cocoa)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2000-12-31T00:00:00+00:00");
+
+        let dt = epoch_family_to_utc("0", EpochFamily::JulianDay)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "-4713-11-24T12:00:00+00:00");
+
+        assert!(epoch_family_to_utc("12:00", EpochFamily::Ntp).is_none());
+    }
+
+    #[test]
+    fn test_gps_leap_seconds() {
+        // GPS epoch itself: no leap seconds yet.
+        let dt = epoch_family_to_utc("0", EpochFamily::Gps).unwrap().unwrap();
+        assert_eq!(dt.to_rfc3339(), "1980-01-06T00:00:00+00:00");
+
+        // 2010-01-01T00:00:00Z, when GPS was 15 s ahead of UTC.
+        let dt = epoch_family_to_utc("946339215", EpochFamily::Gps)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2010-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let err = epoch_family_to_utc("99999999999999999999999", EpochFamily::JulianDay)
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(err, TimeParseError::InvalidFieldValue { .. }));
+    }
+
+    #[test]
+    fn test_utcizer_family_option() {
+        let utcizer = Utcizer::builder()
+            .epoch(EpochOption::Family(EpochFamily::WindowsFileTime))
+            .build();
+
+        let outcome = utcizer.parse_detailed("133300620000000000").unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        assert_eq!(
+            outcome.source,
+            ParseSource::EpochFamily(EpochFamily::WindowsFileTime)
+        );
+        assert_eq!(outcome.epoch_kind, None);
+
+        // Non-numeric input still goes through the formats.
+        assert!(utcizer.parse("2023-06-01 10:00:00").is_ok());
+    }
+}
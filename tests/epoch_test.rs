@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use utcize::datetime::{detect_epoch_kind, epoch_to_utc, split_epoch_suffix, utcize};
+    use utcize::types::{EpochKind, EpochOption, EpochWindow};
+    use utcize::utcizer::Utcizer;
+
+    #[test]
+    fn test_detect_by_magnitude() {
+        assert_eq!(detect_epoch_kind("1685588400"), Some(EpochKind::Seconds));
+        assert_eq!(detect_epoch_kind("999999999"), Some(EpochKind::Seconds));
+        assert_eq!(
+            detect_epoch_kind("978307200000"),
+            Some(EpochKind::Milliseconds)
+        );
+        assert_eq!(
+            detect_epoch_kind("1685588400123"),
+            Some(EpochKind::Milliseconds)
+        );
+        assert_eq!(
+            detect_epoch_kind("1685588400123456"),
+            Some(EpochKind::Microseconds)
+        );
+        assert_eq!(
+            detect_epoch_kind("1685588400123456789"),
+            Some(EpochKind::Nanoseconds)
+        );
+        assert_eq!(detect_epoch_kind("-86400"), Some(EpochKind::Seconds));
+        assert_eq!(detect_epoch_kind("+1685588400"), Some(EpochKind::Seconds));
+        assert_eq!(detect_epoch_kind("20230601100000"), None);
+        assert_eq!(detect_epoch_kind("12a4"), None);
+        assert_eq!(detect_epoch_kind("-"), None);
+    }
+
+    #[test]
+    fn test_short_numbers_are_not_epochs() {
+        for s in [
+            "0", "1", "100", "12345", "2023", "2023152", "20230601", "99999999",
+        ] {
+            assert_eq!(detect_epoch_kind(s), None, "{s}");
+        }
+        assert_eq!(detect_epoch_kind("100000000"), Some(EpochKind::Seconds));
+        // An explicit sign still marks a short value as a pre-1970 epoch.
+        assert_eq!(detect_epoch_kind("-1"), Some(EpochKind::Seconds));
+
+        assert!(utcize::<&str>("12345", "UTC", false, None).is_err());
+        let dt = utcize::<&str>("20230601", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_negative_epochs_floor() {
+        let dt = epoch_to_utc(-1, EpochKind::Milliseconds).unwrap();
+        assert_eq!(dt.to_rfc3339(), "1969-12-31T23:59:59.999+00:00");
+
+        let dt = epoch_to_utc(-1_500_000, EpochKind::Microseconds).unwrap();
+        assert_eq!(dt.to_rfc3339(), "1969-12-31T23:59:58.500+00:00");
+    }
+
+    #[test]
+    fn test_utcize_signed_and_short_epochs() {
+        let dt = utcize::<&str>("999999999", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2001-09-09T01:46:39+00:00");
+
+        let dt = utcize::<&str>("-86400", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "1969-12-31T00:00:00+00:00");
+
+        let dt = utcize::<&str>("+1685588400", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+
+        let dt = utcize::<&str>("978307200000", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2001-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_compact_dates_are_not_epochs() {
+        let dt = utcize::<&str>("20230601100000", "+07:00", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+    }
+
+    #[test]
+    fn test_custom_window() {
+        let window = EpochWindow::new(
+            Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap(),
+        );
+        let utcizer = Utcizer::builder().epoch_window(window).build();
+
+        assert!(utcizer.parse("1685588400").is_ok());
+        assert!(utcizer.parse("-86400").is_err());
+    }
+
+    #[test]
+    fn test_unit_suffixes() {
+        assert_eq!(
+            split_epoch_suffix("1685588400s"),
+            Some((1685588400, EpochKind::Seconds))
+        );
+        assert_eq!(
+            split_epoch_suffix("1685588400123ms"),
+            Some((1685588400123, EpochKind::Milliseconds))
+        );
+        assert_eq!(
+            split_epoch_suffix("-5us"),
+            Some((-5, EpochKind::Microseconds))
+        );
+        assert_eq!(
+            split_epoch_suffix("5µs"),
+            Some((5, EpochKind::Microseconds))
+        );
+        assert_eq!(split_epoch_suffix("5ns"), Some((5, EpochKind::Nanoseconds)));
+        assert_eq!(split_epoch_suffix("ms"), None);
+        assert_eq!(split_epoch_suffix("1685588400"), None);
+
+        let dt = utcize::<&str>("1685588400123ms", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00.123+00:00");
+
+        // A suffix overrides magnitude detection, even outside the window.
+        let dt = utcize::<&str>("20230601100000ns", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "1970-01-01T05:37:10.601100+00:00");
+    }
+
+    #[test]
+    fn test_fixed_unit() {
+        let utcizer = Utcizer::builder()
+            .epoch(EpochOption::Fixed(EpochKind::Milliseconds))
+            .build();
+
+        // 10 digits would be detected as seconds; forced to milliseconds here.
+        let outcome = utcizer.parse_detailed("1685588400").unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "1970-01-20T12:13:08.400+00:00");
+        assert_eq!(outcome.epoch_kind, Some(EpochKind::Milliseconds));
+
+        // An explicit suffix still wins.
+        let dt = utcizer.parse("1685588400s").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+    }
+
+    #[test]
+    fn test_custom_origin() {
+        let origin = Utc.with_ymd_and_hms(2001, 1, 1, 0, 0, 0).unwrap();
+        let utcizer = Utcizer::builder()
+            .epoch(EpochOption::Custom {
+                origin,
+                unit: EpochKind::Seconds,
+            })
+            .build();
+
+        let dt = utcizer.parse("86400").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2001-01-02T00:00:00+00:00");
+
+        let dt = utcizer.parse("-1500ms").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2000-12-31T23:59:58.500+00:00");
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use chrono::format::ParseErrorKind;
+    use utcize::datetime::{parse_datetime_flexible, utcize};
+    use utcize::error::TimeParseError;
+    use utcize::formats::CompiledFormat;
+    use utcize::tz::parse_timezone_str;
+
+    #[test]
+    fn test_timezone_errors() {
+        assert_eq!(
+            parse_timezone_str("Invalid/Zone").unwrap_err(),
+            TimeParseError::UnknownTimezone {
+                name: "Invalid/Zone".into(),
+                suggestions: vec![],
+            }
+        );
+        assert_eq!(
+            parse_timezone_str("/Asia/Jakarta").unwrap_err(),
+            TimeParseError::MalformedTimezone {
+                name: "/Asia/Jakarta".into()
+            }
+        );
+        assert_eq!(
+            parse_timezone_str("+25:00").unwrap_err(),
+            TimeParseError::OffsetOutOfRange {
+                input: "+25:00".into()
+            }
+        );
+        assert_eq!(
+            parse_timezone_str("+07:0").unwrap_err(),
+            TimeParseError::MalformedOffset {
+                input: "+07:0".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_format_matched() {
+        match utcize::<&str>("not a date", "UTC", false, None).unwrap_err() {
+            TimeParseError::NoFormatMatched {
+                input,
+                tried,
+                closest,
+            } => {
+                assert_eq!(input, "not a date");
+                assert!(tried > 2);
+                assert!(matches!(
+                    closest,
+                    Some(ParseErrorKind::Invalid | ParseErrorKind::TooShort)
+                ));
+            }
+            e => panic!("Expected NoFormatMatched, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_field_value() {
+        match utcize::<&str>("2023-02-30 10:00:00", "UTC", false, None).unwrap_err() {
+            TimeParseError::InvalidFieldValue {
+                input,
+                format,
+                kind,
+            } => {
+                assert_eq!(input, "2023-02-30 10:00:00");
+                assert_eq!(format, "%Y-%m-%d %H:%M:%S");
+                assert_eq!(kind, ParseErrorKind::OutOfRange);
+            }
+            e => panic!("Expected InvalidFieldValue, got {e:?}"),
+        }
+
+        match parse_datetime_flexible::<&str>("02/30/2023", false, None).unwrap_err() {
+            TimeParseError::InvalidFieldValue { format, .. } => assert_eq!(format, "%m/%d/%Y"),
+            e => panic!("Expected InvalidFieldValue, got {e:?}"),
+        }
+
+        let err = parse_datetime_flexible::<&str>("2023-06-01 25:00:00", false, None).unwrap_err();
+        assert!(matches!(
+            err,
+            TimeParseError::InvalidFieldValue {
+                kind: ParseErrorKind::OutOfRange,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_partial_matches_are_not_field_errors() {
+        // The trailing word is left over, and reading the year as a month does not count.
+        for input in ["2023-06-01 10:00:00 XYZ", "0", "2023-13-01 garbage"] {
+            match utcize::<&str>(input, "UTC", false, None).unwrap_err() {
+                TimeParseError::NoFormatMatched { .. } => {}
+                e => panic!("Expected NoFormatMatched for {input:?}, got {e:?}"),
+            }
+        }
+
+        match utcize::<&str>("2023-13-01 10:00:00", "UTC", true, None).unwrap_err() {
+            TimeParseError::InvalidFieldValue { format, kind, .. } => {
+                assert_eq!(format, "%Y-%m-%d %H:%M:%S");
+                assert_eq!(kind, ParseErrorKind::OutOfRange);
+            }
+            e => panic!("Expected InvalidFieldValue, got {e:?}"),
+        }
+        // A month out of range still lets the rest of the input be matched.
+        match utcize::<&str>("13/01/2023 10:00", "UTC", false, None).unwrap_err() {
+            TimeParseError::InvalidFieldValue { format, .. } => {
+                assert_eq!(format, "%m/%d/%Y %H:%M")
+            }
+            e => panic!("Expected InvalidFieldValue, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_datetime_out_of_range() {
+        for input in ["+262142-12-31 23:30:00 UTC-1", "+262142-12-31 23:30:00 EST"] {
+            match utcize::<&str>(input, "UTC", false, None).unwrap_err() {
+                TimeParseError::DatetimeOutOfRange { datetime, offset } => {
+                    assert_eq!(datetime.to_string(), "+262142-12-31 23:30:00");
+                    assert!(offset.local_minus_utc() < 0);
+                }
+                e => panic!("Expected DatetimeOutOfRange for {input:?}, got {e:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_format() {
+        assert_eq!(
+            CompiledFormat::new("%Y-%Q").unwrap_err(),
+            TimeParseError::InvalidFormat {
+                format: "%Y-%Q".into()
+            }
+        );
+    }
+}
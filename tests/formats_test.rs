@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Timelike};
+    use utcize::datetime::parse_datetime_flexible;
+    use utcize::formats::{CompiledFormat, CompiledFormats, default_formats};
+    use utcize::types::ParsedDatetime;
+
+    #[test]
+    fn test_compiled_format_naive_and_offset() {
+        let naive = CompiledFormat::new("%Y-%m-%d %H:%M:%S").unwrap();
+        match naive.parse("2023-06-01 10:00:00").unwrap() {
+            ParsedDatetime::Naive(ndt) => assert_eq!(ndt.hour(), 10),
+            _ => panic!("Expected Naive"),
+        }
+
+        let with_offset = CompiledFormat::new("%Y-%m-%d %H:%M:%S%z").unwrap();
+        match with_offset.parse("2023-06-01 10:00:00+0700").unwrap() {
+            ParsedDatetime::WithTimezone(dt) => {
+                assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00")
+            }
+            _ => panic!("Expected WithTimezone"),
+        }
+        assert!(with_offset.parse("2023-06-01 10:00:00").is_err());
+    }
+
+    #[test]
+    fn test_compiled_format_date_only_is_midnight() {
+        let fmt = CompiledFormat::new("%d.%m.%Y").unwrap();
+        match fmt.parse("01.06.2023").unwrap() {
+            ParsedDatetime::Naive(ndt) => {
+                assert_eq!(
+                    ndt,
+                    NaiveDate::from_ymd_opt(2023, 6, 1)
+                        .unwrap()
+                        .and_hms_opt(0, 0, 0)
+                        .unwrap()
+                )
+            }
+            _ => panic!("Expected Naive"),
+        }
+        assert_eq!(fmt.as_str(), "%d.%m.%Y");
+    }
+
+    #[test]
+    fn test_invalid_format_is_rejected() {
+        assert!(CompiledFormat::new("%Y-%Q").is_err());
+
+        let formats = CompiledFormats::new(["%Y-%Q", "%Y|%m|%d"]);
+        assert_eq!(formats.len(), 1);
+        assert!(formats.parse("2023|06|01").is_some());
+    }
+
+    #[test]
+    fn test_defaults_match_default_formats() {
+        for prefer_eu in [true, false] {
+            let compiled = CompiledFormats::defaults(prefer_eu);
+            let names: Vec<&str> = compiled.iter().map(|f| f.as_str()).collect();
+            assert_eq!(names, default_formats(prefer_eu));
+        }
+    }
+
+    #[test]
+    fn test_flexible_custom_formats_first() {
+        let parsed =
+            parse_datetime_flexible("2023|06|01 10:00", false, Some(&["%Y|%m|%d %H:%M"])).unwrap();
+        assert!(matches!(parsed, ParsedDatetime::Naive(_)));
+
+        let err = parse_datetime_flexible::<&str>("not a date", false, None).unwrap_err();
+        assert!(format!("{}", err).contains("No matching format found"));
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use utcize::datetime::{parse_datetime_flexible, utcize, utcize_detailed};
+    use utcize::error::TimeParseError;
+    use utcize::types::{AmbiguityPolicy, DateOrder, ParsedDatetime};
+    use utcize::tz::parse_timezone_str;
+    use utcize::utcizer::Utcizer;
+
+    #[test]
+    fn test_trailing_zone() {
+        let dt = utcize::<&str>("2023-06-01 10:00:00 Asia/Jakarta", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+    }
+
+    #[test]
+    fn test_leading_zone() {
+        let utcizer = Utcizer::builder().date_order(DateOrder::DayFirst).build();
+        let dt = utcizer.parse("Europe/Berlin 01.06.2023 10:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_zone_takes_precedence_over_fallback() {
+        let outcome = utcize_detailed::<&str>(
+            "2023-06-01 10:00:00 Asia/Tokyo",
+            "Europe/Berlin",
+            false,
+            None,
+        )
+        .unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T01:00:00+00:00");
+        assert_eq!(outcome.offset.local_minus_utc(), 9 * 3600);
+        assert!(!outcome.used_fallback);
+    }
+
+    #[test]
+    fn test_flexible_resolves_zone() {
+        let parsed =
+            parse_datetime_flexible::<&str>("2023-06-01 10:00:00 America/New_York", false, None)
+                .unwrap();
+        match parsed {
+            ParsedDatetime::WithTimezone(dt) => {
+                assert_eq!(dt.to_rfc3339(), "2023-06-01T14:00:00+00:00")
+            }
+            other => panic!("Expected WithTimezone, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_zone_ambiguity_follows_policy() {
+        let input = "2023-10-29 02:30:00 Europe/Berlin";
+        assert!(matches!(
+            utcize::<&str>(input, "UTC", false, None),
+            Err(TimeParseError::AmbiguousTime { .. })
+        ));
+
+        let utcizer = Utcizer::builder()
+            .ambiguity_policy(AmbiguityPolicy::Latest)
+            .build();
+        assert_eq!(
+            utcizer.parse(input).unwrap().to_rfc3339(),
+            "2023-10-29T01:30:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_zone_gap_is_reported() {
+        match utcize::<&str>("2023-03-26 02:30:00 Europe/Berlin", "UTC", false, None).unwrap_err() {
+            TimeParseError::NonexistentTime { tz, .. } => assert_eq!(tz, "Europe/Berlin"),
+            e => panic!("Expected NonexistentTime, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_slash_dates_are_not_zones() {
+        let utcizer = Utcizer::builder()
+            .fallback_tz(parse_timezone_str("Asia/Jakarta").unwrap())
+            .build();
+        let dt = utcizer.parse("06/01/2023 10:00:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+
+        assert!(utcizer.parse("2023-06-01 10:00:00 Mars/Olympus").is_err());
+    }
+}
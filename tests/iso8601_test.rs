@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use chrono::format::ParseErrorKind;
+    use utcize::datetime::{parse_datetime_flexible, utcize, utcize_detailed};
+    use utcize::error::TimeParseError;
+    use utcize::iso8601::parse_iso8601;
+    use utcize::types::{ParseSource, ParsedDatetime};
+
+    fn naive(s: &str) -> String {
+        match parse_iso8601(s).unwrap() {
+            ParsedDatetime::Naive(dt) => dt.to_string(),
+            other => panic!("Expected Naive, got {other:?}"),
+        }
+    }
+
+    fn utc(s: &str) -> String {
+        utcize::<&str>(s, "UTC", false, None).unwrap().to_rfc3339()
+    }
+
+    #[test]
+    fn test_ordinal_and_week_dates() {
+        assert_eq!(naive("2023-152"), "2023-06-01 00:00:00");
+        assert_eq!(naive("2023152T1000"), "2023-06-01 10:00:00");
+        assert_eq!(naive("2024-366T23:59"), "2024-12-31 23:59:00");
+        assert_eq!(naive("2023-W22-4T10"), "2023-06-01 10:00:00");
+        assert_eq!(naive("2023W224"), "2023-06-01 00:00:00");
+        assert_eq!(naive("2023-W22"), "2023-05-29 00:00:00");
+    }
+
+    #[test]
+    fn test_reduced_precision() {
+        assert_eq!(naive("2023-06"), "2023-06-01 00:00:00");
+        assert_eq!(naive("2023"), "2023-01-01 00:00:00");
+        assert_eq!(naive("2023-06-01T10"), "2023-06-01 10:00:00");
+        assert_eq!(naive("20230601T10"), "2023-06-01 10:00:00");
+        assert_eq!(utc("2023-06-01T10:00Z"), "2023-06-01T10:00:00+00:00");
+        assert_eq!(utc("2023-06-01T10+07"), "2023-06-01T03:00:00+00:00");
+        assert_eq!(utc("20230601T1000-0230"), "2023-06-01T12:30:00+00:00");
+        // A time needs a complete date.
+        assert!(parse_iso8601("2023-06T10").is_err());
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_eq!(naive("2023-06-01T10:00:00,5"), "2023-06-01 10:00:00.500");
+        assert_eq!(
+            naive("2023-06-01T10:00:00.123456789"),
+            "2023-06-01 10:00:00.123456789"
+        );
+        assert_eq!(naive("2023-06-01T10.5"), "2023-06-01 10:30:00");
+        assert_eq!(naive("2023-06-01T10,25"), "2023-06-01 10:15:00");
+        assert_eq!(naive("2023-06-01T10:30.5"), "2023-06-01 10:30:30");
+        assert_eq!(naive("20230601T1030,5"), "2023-06-01 10:30:30");
+        assert_eq!(naive("2023-06-01T10.1"), "2023-06-01 10:06:00");
+        assert_eq!(
+            utc("2023-06-01T10:00:00,5+07:00"),
+            "2023-06-01T03:00:00.500+00:00"
+        );
+    }
+
+    #[test]
+    fn test_end_of_day_and_leap_second() {
+        assert_eq!(naive("2023-06-01T24:00:00"), "2023-06-02 00:00:00");
+        assert_eq!(naive("2023-12-31T24:00"), "2024-01-01 00:00:00");
+        assert_eq!(naive("2023-06-01T24"), "2023-06-02 00:00:00");
+        assert_eq!(
+            utc("2023-06-01T24:00:00+07:00"),
+            "2023-06-01T17:00:00+00:00"
+        );
+        assert_eq!(naive("2016-12-31T23:59:60"), "2016-12-31 23:59:60");
+
+        for out_of_range in [
+            "2023-06-01T24:00:01",
+            "2023-06-01T24,5",
+            "2023-06-01T10:60",
+            "2023-06-01T10:00+24",
+            "2023-13-01",
+            "2023-366",
+            "2023-W54-1",
+            "2023-W22-8",
+        ] {
+            match parse_iso8601(out_of_range).unwrap_err() {
+                TimeParseError::InvalidFieldValue { format, kind, .. } => {
+                    assert_eq!(format, "ISO 8601", "{out_of_range}");
+                    assert_eq!(kind, ParseErrorKind::OutOfRange, "{out_of_range}");
+                }
+                e => panic!("Expected InvalidFieldValue for {out_of_range}, got {e:?}"),
+            }
+        }
+        assert!(matches!(
+            parse_iso8601("June 2023"),
+            Err(TimeParseError::NoFormatMatched { tried: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_pipeline_uses_iso8601() {
+        let outcome = utcize_detailed::<&str>("2023-152T10:00", "+07:00", false, None).unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        assert_eq!(outcome.source, ParseSource::Iso8601);
+        assert!(outcome.used_fallback);
+
+        // Custom formats still come first, and inline zones apply.
+        let custom = ["%Y-%j"];
+        let outcome = utcize_detailed("2023-152", "UTC", false, Some(&custom)).unwrap();
+        assert_eq!(outcome.source, ParseSource::CustomFormat("%Y-%j".into()));
+        let dt = utcize::<&str>("2023-06-01T10 Asia/Jakarta", "UTC", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+
+        assert!(matches!(
+            parse_datetime_flexible::<&str>("2023-06-01T10:00:00,5", true, None).unwrap(),
+            ParsedDatetime::Naive(_)
+        ));
+    }
+
+    #[test]
+    fn test_digit_only_forms_are_not_epochs() {
+        for (input, expected) in [
+            ("20230601", "2023-06-01T00:00:00+00:00"),
+            ("2023152", "2023-06-01T00:00:00+00:00"),
+            ("2023", "2023-01-01T00:00:00+00:00"),
+        ] {
+            let outcome = utcize_detailed::<&str>(input, "UTC", false, None).unwrap();
+            assert_eq!(outcome.utc.to_rfc3339(), expected, "{input}");
+            assert_eq!(outcome.source, ParseSource::Iso8601, "{input}");
+            assert_eq!(outcome.epoch_kind, None, "{input}");
+        }
+        assert_eq!(utc("20230601"), "2023-06-01T00:00:00+00:00");
+        let dt = utcize::<&str>("2023152", "+07:00", true, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-05-31T17:00:00+00:00");
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Month, Weekday};
+    use utcize::datetime::{parse_datetime_flexible, utcize};
+    use utcize::error::TimeParseError;
+    use utcize::locale::{Locale, lookup_month, lookup_weekday, translate_names};
+    use utcize::utcizer::Utcizer;
+
+    fn utc(s: &str) -> String {
+        utcize::<&str>(s, "UTC", true, None).unwrap().to_rfc3339()
+    }
+
+    #[test]
+    fn test_default_formats_with_localized_names() {
+        assert_eq!(utc("1 Juni 2023"), "2023-06-01T00:00:00+00:00");
+        assert_eq!(utc("01 Mei 2023 10:00"), "2023-05-01T10:00:00+00:00");
+        assert_eq!(utc("1. März 2023"), "2023-03-01T00:00:00+00:00");
+        assert_eq!(utc("1er juin 2023"), "2023-06-01T00:00:00+00:00");
+        assert_eq!(utc("1 de junio de 2023"), "2023-06-01T00:00:00+00:00");
+        assert_eq!(utc("1 juni 2023 10:00:00"), "2023-06-01T10:00:00+00:00");
+        assert_eq!(utc("1º de junho de 2023"), "2023-06-01T00:00:00+00:00");
+        assert_eq!(utc("1 giugno 2023"), "2023-06-01T00:00:00+00:00");
+        assert_eq!(utc("15 févr. 2023"), "2023-02-15T00:00:00+00:00");
+        assert_eq!(utc("3 Okt 2023"), "2023-10-03T00:00:00+00:00");
+
+        let dt = utcize::<&str>("01 Mei 2023 10:00", "Asia/Jakarta", true, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-05-01T03:00:00+00:00");
+    }
+
+    #[test]
+    fn test_localized_names_in_both_date_orders() {
+        for prefer_eu in [true, false] {
+            let utc = |s: &str| {
+                utcize::<&str>(s, "UTC", prefer_eu, None)
+                    .unwrap()
+                    .to_rfc3339()
+            };
+            assert_eq!(utc("1 juin 2023"), "2023-06-01T00:00:00+00:00");
+            assert_eq!(utc("01 Mei 2023 10:00"), "2023-05-01T10:00:00+00:00");
+            assert_eq!(utc("15 févr. 2023"), "2023-02-15T00:00:00+00:00");
+            assert_eq!(utc("jeudi 1 juin 2023"), "2023-06-01T00:00:00+00:00");
+            assert_eq!(utc("Donnerstag, 1. Juni 2023"), "2023-06-01T00:00:00+00:00");
+            assert_eq!(
+                utc("Jeu, 01 juin 2023 10:00:00 +0200"),
+                "2023-06-01T08:00:00+00:00"
+            );
+            assert_eq!(
+                utc("jueves, 1 de junio de 2023"),
+                "2023-06-01T00:00:00+00:00"
+            );
+        }
+    }
+
+    #[test]
+    fn test_case_and_diacritic_insensitive() {
+        assert_eq!(lookup_month("MÄRZ", &Locale::ALL), Some(Month::March));
+        assert_eq!(lookup_month("marz", &Locale::ALL), Some(Month::March));
+        assert_eq!(lookup_month("Maerz", &Locale::ALL), Some(Month::March));
+        assert_eq!(
+            lookup_month("décembre", &Locale::ALL),
+            Some(Month::December)
+        );
+        assert_eq!(lookup_month("MRT", &[Locale::Dutch]), Some(Month::March));
+        assert_eq!(
+            lookup_weekday("Miércoles", &Locale::ALL),
+            Some(Weekday::Wed)
+        );
+        assert_eq!(lookup_weekday("jumat", &Locale::ALL), Some(Weekday::Fri));
+        assert_eq!(lookup_weekday("Thu", &[]), Some(Weekday::Thu));
+        assert_eq!(lookup_month("Juni", &[]), None);
+        assert_eq!(lookup_month("Mei", &[Locale::German]), None);
+
+        assert_eq!(Locale::from_code("DE"), Some(Locale::German));
+        assert_eq!(Locale::Indonesian.code(), "id");
+        assert_eq!(Locale::from_code("xx"), None);
+    }
+
+    #[test]
+    fn test_translate_names() {
+        let all = Locale::ALL;
+        assert_eq!(
+            translate_names("Donnerstag, 1. Juni 2023", &all).unwrap(),
+            "Thu, 1 Jun 2023"
+        );
+        assert_eq!(
+            translate_names("segunda-feira, 5 de junho de 2023", &all).unwrap(),
+            "Mon, 5 Jun 2023"
+        );
+        assert_eq!(translate_names("Kamis", &all).unwrap(), "Thu");
+        assert_eq!(
+            translate_names("Do., 1. Okt. 2023", &all).unwrap(),
+            "Thu, 1 Oct 2023"
+        );
+        // English names and other words are left alone.
+        assert_eq!(
+            translate_names("Thu, 01 Jun 2023 10:00:00 +0700", &all),
+            None
+        );
+        assert_eq!(translate_names("2023-06-01T10:00:00Z", &all), None);
+    }
+
+    #[test]
+    fn test_custom_formats_with_localized_names() {
+        let custom = ["%A, %d %B %Y %H:%M", "%d/%b/%Y"];
+        let dt = utcize(
+            "Donnerstag, 1. Juni 2023 10:00",
+            "UTC",
+            false,
+            Some(&custom),
+        )
+        .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T10:00:00+00:00");
+        let dt = utcize("01/Okt/2023", "UTC", false, Some(&custom)).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-10-01T00:00:00+00:00");
+        // Full names are abbreviated too, which `%b` needs.
+        let dt = utcize("01/Oktober/2023", "UTC", false, Some(&custom)).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-10-01T00:00:00+00:00");
+
+        // The weekday still has to match the date.
+        assert!(matches!(
+            utcize("Freitag, 1. Juni 2023 10:00", "UTC", false, Some(&custom)),
+            Err(TimeParseError::InvalidFieldValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_utcizer_locales() {
+        let german = Utcizer::builder().locales([Locale::German]).build();
+        assert_eq!(german.locales(), [Locale::German]);
+        assert!(german.parse("1. März 2023 10:00").is_ok());
+        assert!(german.parse("01 Mei 2023 10:00").is_err());
+
+        let english = Utcizer::builder().locales([]).build();
+        assert!(english.parse("1 Juni 2023 10:00").is_err());
+        assert!(parse_datetime_flexible::<&str>("1 Juni 2023", true, None).is_ok());
+    }
+}
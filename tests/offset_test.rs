@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use utcize::datetime::utcize;
+    use utcize::error::TimeParseError;
+    use utcize::types::TimeZoneParsed;
+    use utcize::tz::{TimezoneOptions, parse_offset, parse_timezone_str, parse_timezone_str_with};
+    use utcize::utcizer::Utcizer;
+
+    fn offset_seconds(s: &str) -> i32 {
+        match parse_timezone_str(s).unwrap() {
+            TimeZoneParsed::FixedOffset(offset) => offset.local_minus_utc(),
+            other => panic!("Expected FixedOffset for {s}, got {other:?}"),
+        }
+    }
+
+    fn rfc3339(s: &str) -> String {
+        utcize::<&str>(s, "Asia/Jakarta", false, None)
+            .unwrap()
+            .to_rfc3339()
+    }
+
+    #[test]
+    fn test_offset_notations() {
+        assert_eq!(offset_seconds("UTC+7"), 7 * 3600);
+        assert_eq!(offset_seconds("utc-3"), -3 * 3600);
+        assert_eq!(offset_seconds("GMT-05:30"), -(5 * 3600 + 30 * 60));
+        assert_eq!(offset_seconds("UT+0530"), 5 * 3600 + 30 * 60);
+        assert_eq!(offset_seconds("+7"), 7 * 3600);
+        assert_eq!(offset_seconds("+07"), 7 * 3600);
+        assert_eq!(offset_seconds("+530"), 5 * 3600 + 30 * 60);
+        assert_eq!(offset_seconds("-0800"), -8 * 3600);
+        assert_eq!(offset_seconds("Z"), 0);
+        assert_eq!(offset_seconds("+07:07:12"), 7 * 3600 + 7 * 60 + 12);
+        assert_eq!(offset_seconds("+070712"), 7 * 3600 + 7 * 60 + 12);
+        assert_eq!(offset_seconds("GMT+0"), 0);
+    }
+
+    #[test]
+    fn test_out_of_range_offsets() {
+        for input in ["+24:00", "UTC+25", "+07:60", "-05:30:60"] {
+            assert_eq!(
+                parse_offset(input).unwrap_err(),
+                TimeParseError::OffsetOutOfRange {
+                    input: input.into()
+                },
+                "{input}"
+            );
+        }
+        for input in ["+", "+07:5", "+12345", "UTC+7x", "+07:00:00:00"] {
+            assert!(
+                matches!(
+                    parse_offset(input),
+                    Err(TimeParseError::MalformedOffset { .. })
+                ),
+                "{input}"
+            );
+        }
+        assert!(matches!(
+            utcize::<&str>("2023-06-01 10:00:00", "UTC+25", false, None),
+            Err(TimeParseError::OffsetOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_js_minute_offsets() {
+        let js = TimezoneOptions {
+            js_minute_offsets: true,
+            ..Default::default()
+        };
+        let seconds = |s| match parse_timezone_str_with(s, &js).unwrap() {
+            TimeZoneParsed::FixedOffset(offset) => offset.local_minus_utc(),
+            other => panic!("Expected FixedOffset, got {other:?}"),
+        };
+        assert_eq!(seconds("-420"), 7 * 3600);
+        assert_eq!(seconds("300"), -5 * 3600);
+        assert_eq!(seconds("+60"), -3600);
+        assert_eq!(seconds("0"), 0);
+        assert_eq!(seconds("UTC+7"), 7 * 3600);
+
+        // Zero-padded hours and four-digit offsets are not minutes.
+        assert_eq!(seconds("+0700"), 7 * 3600);
+        assert_eq!(seconds("+07"), 7 * 3600);
+        assert_eq!(seconds("-07:00"), -7 * 3600);
+        assert_eq!(seconds("-1440"), -(14 * 3600 + 40 * 60));
+
+        // Without the option, -420 is -04:20.
+        assert_eq!(offset_seconds("-420"), -(4 * 3600 + 20 * 60));
+    }
+
+    #[test]
+    fn test_offset_suffixes() {
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00 UTC+7"),
+            "2023-06-01T03:00:00+00:00"
+        );
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00 GMT-05:30"),
+            "2023-06-01T15:30:00+00:00"
+        );
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00+7"),
+            "2023-06-01T03:00:00+00:00"
+        );
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00 +07"),
+            "2023-06-01T03:00:00+00:00"
+        );
+        assert_eq!(rfc3339("2023-06-01 10:00:00Z"), "2023-06-01T10:00:00+00:00");
+        assert_eq!(rfc3339("06/01/2023 10:00 Z"), "2023-06-01T10:00:00+00:00");
+        assert_eq!(
+            rfc3339("2023-06-01 10:00:00 +07:07:12"),
+            "2023-06-01T02:52:48+00:00"
+        );
+        assert!(matches!(
+            utcize::<&str>("2023-06-01 10:00:00 UTC+25", "UTC", false, None),
+            Err(TimeParseError::OffsetOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_dates_are_not_offsets() {
+        assert_eq!(rfc3339("2023-06-01"), "2023-05-31T17:00:00+00:00");
+        assert_eq!(rfc3339("06-01-2023 10:00"), "2023-06-01T03:00:00+00:00");
+    }
+
+    #[test]
+    fn test_js_minute_suffixes() {
+        let utcizer = Utcizer::builder().js_minute_offsets(true).build();
+        let dt = utcizer.parse("2023-06-01 10:00:00 -420").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        let dt = utcizer.parse("2023-06-01 10:00:00 +0700").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+
+        let utcizer = Utcizer::builder().build();
+        let dt = utcizer.parse("2023-06-01 10:00:00 -420").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T14:20:00+00:00");
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use utcize::datetime::utcize_detailed;
+    use utcize::types::{EpochKind, ParseSource};
+
+    #[test]
+    fn test_epoch_outcome() {
+        let outcome =
+            utcize_detailed::<&str>("1685588400123", "Asia/Jakarta", false, None).unwrap();
+        assert_eq!(outcome.source, ParseSource::Epoch);
+        assert_eq!(outcome.epoch_kind, Some(EpochKind::Milliseconds));
+        assert_eq!(outcome.offset.local_minus_utc(), 0);
+        assert!(!outcome.used_fallback);
+    }
+
+    #[test]
+    fn test_rfc_outcomes() {
+        let outcome =
+            utcize_detailed::<&str>("2023-06-01T10:00:00+07:00", "UTC", false, None).unwrap();
+        assert_eq!(outcome.source, ParseSource::Rfc3339);
+        assert_eq!(outcome.offset.local_minus_utc(), 7 * 3600);
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        assert_eq!(outcome.epoch_kind, None);
+
+        let outcome =
+            utcize_detailed::<&str>("Thu, 01 Jun 2023 10:00:00 -0500", "UTC", false, None).unwrap();
+        assert_eq!(outcome.source, ParseSource::Rfc2822);
+        assert_eq!(outcome.offset.local_minus_utc(), -5 * 3600);
+        assert!(!outcome.used_fallback);
+    }
+
+    #[test]
+    fn test_default_format_with_fallback() {
+        let outcome =
+            utcize_detailed::<&str>("01-06-2023 10:00:00", "Europe/Berlin", true, None).unwrap();
+        assert_eq!(
+            outcome.source,
+            ParseSource::DefaultFormat("%d-%m-%Y %H:%M:%S".into())
+        );
+        assert_eq!(outcome.offset.local_minus_utc(), 2 * 3600);
+        assert!(outcome.used_fallback);
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_format_with_offset_does_not_use_fallback() {
+        let outcome =
+            utcize_detailed::<&str>("2023-06-01 10:00:00+0700", "Europe/Berlin", false, None)
+                .unwrap();
+        assert_eq!(
+            outcome.source,
+            ParseSource::DefaultFormat("%Y-%m-%d %H:%M:%S%z".into())
+        );
+        assert_eq!(outcome.offset.local_minus_utc(), 7 * 3600);
+        assert!(!outcome.used_fallback);
+    }
+
+    #[test]
+    fn test_custom_format_outcome() {
+        let custom = ["%Y|%m|%d %H:%M"];
+        let outcome = utcize_detailed("2023|06|01 10:00", "+07:00", false, Some(&custom)).unwrap();
+        assert_eq!(
+            outcome.source,
+            ParseSource::CustomFormat("%Y|%m|%d %H:%M".into())
+        );
+        assert!(outcome.used_fallback);
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use utcize::error::TimeParseError;
+    use utcize::types::{AmbiguityPolicy, GapPolicy};
+    use utcize::tz::parse_timezone_str;
+    use utcize::utcizer::Utcizer;
+
+    // 2023-11-05 01:30 happens twice in New York: first on EDT (-04:00), then on EST (-05:00).
+    const AMBIGUOUS: &str = "2023-11-05 01:30:00";
+
+    fn new_york(policy: AmbiguityPolicy) -> Utcizer {
+        Utcizer::builder()
+            .fallback_tz(parse_timezone_str("America/New_York").unwrap())
+            .ambiguity_policy(policy)
+            .build()
+    }
+
+    #[test]
+    fn test_default_policy_is_error() {
+        let utcizer = Utcizer::builder()
+            .fallback_tz(parse_timezone_str("America/New_York").unwrap())
+            .build();
+        assert_eq!(utcizer.ambiguity_policy(), AmbiguityPolicy::Error);
+
+        match utcizer.parse(AMBIGUOUS).unwrap_err() {
+            TimeParseError::AmbiguousTime { options, .. } => {
+                assert_eq!(options[0].to_rfc3339(), "2023-11-05T05:30:00+00:00");
+                assert_eq!(options[1].to_rfc3339(), "2023-11-05T06:30:00+00:00");
+            }
+            e => panic!("Expected AmbiguousTime, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_earliest_and_latest() {
+        let dt = new_york(AmbiguityPolicy::Earliest)
+            .parse(AMBIGUOUS)
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-11-05T05:30:00+00:00");
+
+        let dt = new_york(AmbiguityPolicy::Latest).parse(AMBIGUOUS).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-11-05T06:30:00+00:00");
+    }
+
+    #[test]
+    fn test_prefer_standard_and_daylight() {
+        let outcome = new_york(AmbiguityPolicy::PreferStandard)
+            .parse_detailed(AMBIGUOUS)
+            .unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-11-05T06:30:00+00:00");
+        assert_eq!(outcome.offset.local_minus_utc(), -5 * 3600);
+
+        let outcome = new_york(AmbiguityPolicy::PreferDaylight)
+            .parse_detailed(AMBIGUOUS)
+            .unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-11-05T05:30:00+00:00");
+        assert_eq!(outcome.offset.local_minus_utc(), -4 * 3600);
+    }
+
+    #[test]
+    fn test_policy_does_not_affect_unambiguous_times() {
+        let dt = new_york(AmbiguityPolicy::Latest)
+            .parse("2023-06-01 10:00:00")
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T14:00:00+00:00");
+    }
+
+    // 2023-03-26 02:30 does not exist in Berlin: clocks jump from 02:00 (+01:00) to 03:00 (+02:00).
+    const NONEXISTENT: &str = "2023-03-26 02:30:00";
+
+    fn berlin(policy: GapPolicy) -> Utcizer {
+        Utcizer::builder()
+            .fallback_tz(parse_timezone_str("Europe/Berlin").unwrap())
+            .gap_policy(policy)
+            .build()
+    }
+
+    #[test]
+    fn test_default_gap_policy_is_error() {
+        let utcizer = berlin(GapPolicy::default());
+        assert_eq!(utcizer.gap_policy(), GapPolicy::Error);
+
+        match utcizer.parse(NONEXISTENT).unwrap_err() {
+            TimeParseError::NonexistentTime {
+                datetime,
+                tz,
+                gap_start,
+                gap_end,
+            } => {
+                let day = NaiveDate::from_ymd_opt(2023, 3, 26).unwrap();
+                assert_eq!(datetime, day.and_hms_opt(2, 30, 0).unwrap());
+                assert_eq!(tz, "Europe/Berlin");
+                assert_eq!(gap_start, day.and_hms_opt(2, 0, 0).unwrap());
+                assert_eq!(gap_end, day.and_hms_opt(3, 0, 0).unwrap());
+            }
+            e => panic!("Expected NonexistentTime, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_gap_shift_policies() {
+        let dt = berlin(GapPolicy::ShiftForward).parse(NONEXISTENT).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-03-26T01:00:00+00:00");
+
+        let outcome = berlin(GapPolicy::ShiftBackward)
+            .parse_detailed(NONEXISTENT)
+            .unwrap();
+        assert_eq!(
+            outcome.utc.to_rfc3339(),
+            "2023-03-26T00:59:59.999999999+00:00"
+        );
+        assert_eq!(outcome.offset.local_minus_utc(), 3600);
+    }
+
+    #[test]
+    fn test_gap_offset_policies() {
+        let outcome = berlin(GapPolicy::UseOffsetBefore)
+            .parse_detailed(NONEXISTENT)
+            .unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-03-26T01:30:00+00:00");
+        assert_eq!(outcome.offset.local_minus_utc(), 3600);
+
+        let outcome = berlin(GapPolicy::UseOffsetAfter)
+            .parse_detailed(NONEXISTENT)
+            .unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-03-26T00:30:00+00:00");
+        assert_eq!(outcome.offset.local_minus_utc(), 2 * 3600);
+    }
+}
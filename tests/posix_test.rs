@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+    use utcize::datetime::utcize;
+    use utcize::error::TimeParseError;
+    use utcize::posix::parse_posix_tz;
+    use utcize::provider::{BundledProvider, TimeZoneProvider, TzifProvider, ZoneRules};
+    use utcize::types::TimeZoneParsed;
+    use utcize::tz::parse_timezone_str;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    /// Checks that the POSIX rules agree with the bundled zone every hour of `year`.
+    fn assert_matches_bundled(posix: &str, iana: &str, year: i32) {
+        let rules = parse_posix_tz(posix).unwrap();
+        let zone = BundledProvider.zone(iana).unwrap();
+        let mut utc = at(year, 1, 1, 0, 0);
+        while utc < at(year + 1, 1, 1, 0, 0) {
+            assert_eq!(
+                rules.offset_at(&utc),
+                zone.offset_at(&utc),
+                "{posix} at {utc}"
+            );
+            utc += TimeDelta::hours(1);
+        }
+    }
+
+    #[test]
+    fn test_parse_posix_strings() {
+        let wib = parse_posix_tz("WIB-7").unwrap();
+        assert_eq!(wib.std_abbreviation(), "WIB");
+        assert_eq!(wib.std_offset().local_minus_utc(), 7 * 3600);
+        assert_eq!(wib.dst_abbreviation(), None);
+
+        let quoted = parse_posix_tz("<+0530>-5:30").unwrap();
+        assert_eq!(quoted.std_abbreviation(), "+0530");
+        assert_eq!(quoted.std_offset().local_minus_utc(), 5 * 3600 + 30 * 60);
+
+        let berlin = parse_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert_eq!(berlin.as_str(), "CET-1CEST,M3.5.0,M10.5.0/3");
+        assert_eq!(berlin.dst_abbreviation(), Some("CEST"));
+        assert_eq!(berlin.dst_offset().unwrap().local_minus_utc(), 2 * 3600);
+
+        // Without rules, daylight saving time follows the US rules.
+        let eastern = parse_posix_tz("EST5EDT").unwrap();
+        assert!(eastern.offset_at(&at(2023, 7, 1, 12, 0)).is_dst);
+        assert!(!eastern.offset_at(&at(2023, 12, 1, 12, 0)).is_dst);
+
+        for malformed in [
+            "CET",
+            "CE-1",
+            "CET-25",
+            "CET-1CEST,M13.5.0,M10.5.0",
+            "CET-1CEST,M3.5.0",
+            "CET-1CEST,J0,J365",
+            "<+07-7",
+            "WIB-7 extra",
+        ] {
+            assert!(
+                matches!(
+                    parse_posix_tz(malformed),
+                    Err(TimeParseError::MalformedTimezone { .. })
+                ),
+                "{malformed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rules_match_tz_database() {
+        assert_matches_bundled("CET-1CEST,M3.5.0,M10.5.0/3", "Europe/Berlin", 2023);
+        assert_matches_bundled("EST5EDT,M3.2.0,M11.1.0", "America/New_York", 2024);
+        assert_matches_bundled("AEST-10AEDT,M10.1.0,M4.1.0/3", "Australia/Sydney", 2023);
+        assert_matches_bundled("<-02>2<-01>,M3.5.0/-1,M10.5.0/0", "America/Nuuk", 2025);
+        assert_matches_bundled("<-04>4<-03>,M9.1.6/24,M4.1.6/24", "America/Santiago", 2025);
+    }
+
+    #[test]
+    fn test_julian_and_all_year_rules() {
+        // `J60` is March 1 in every year, `59` is March 1 only in leap years.
+        let julian = parse_posix_tz("XST0XDT,J60/0,J300/0").unwrap();
+        assert!(julian.offset_at(&at(2024, 3, 1, 0, 0)).is_dst);
+        assert!(!julian.offset_at(&at(2024, 2, 29, 23, 0)).is_dst);
+        let zero_based = parse_posix_tz("XST0XDT,59/0,300/0").unwrap();
+        assert!(zero_based.offset_at(&at(2024, 2, 29, 0, 0)).is_dst);
+        assert!(!zero_based.offset_at(&at(2023, 2, 28, 23, 0)).is_dst);
+
+        // Daylight saving time all year round, as used in TZif footers.
+        let all_year = parse_posix_tz("EST5EDT4,0/0,J365/25").unwrap();
+        for utc in [
+            at(2023, 1, 1, 0, 0),
+            at(2023, 6, 1, 0, 0),
+            at(2023, 12, 31, 23, 0),
+        ] {
+            assert!(all_year.offset_at(&utc).is_dst, "{utc}");
+        }
+    }
+
+    #[test]
+    fn test_parse_timezone_str_accepts_posix() {
+        match parse_timezone_str("CET-1CEST,M3.5.0,M10.5.0/3").unwrap() {
+            TimeZoneParsed::Posix(tz) => assert_eq!(tz.std_abbreviation(), "CET"),
+            other => panic!("Expected Posix, got {other:?}"),
+        }
+        match parse_timezone_str("WIB-7").unwrap() {
+            TimeZoneParsed::Posix(tz) => assert_eq!(tz.std_offset().local_minus_utc(), 7 * 3600),
+            other => panic!("Expected Posix, got {other:?}"),
+        }
+        // IANA names that happen to be valid POSIX strings stay IANA zones.
+        assert!(matches!(
+            parse_timezone_str("EST5EDT").unwrap(),
+            TimeZoneParsed::Iana(_)
+        ));
+    }
+
+    #[test]
+    fn test_utcize_with_posix_fallback() {
+        let posix = "CET-1CEST,M3.5.0,M10.5.0/3";
+        let dt = utcize::<&str>("2023-06-01 10:00:00", posix, false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+        let dt = utcize::<&str>("2023-06-01 10:00:00", "WIB-7", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+
+        // Gaps and ambiguous times are reported exactly as for the IANA zone.
+        for local in ["2023-03-26 02:30:00", "2023-10-29 02:30:00"] {
+            let from_posix = utcize::<&str>(local, posix, false, None).unwrap_err();
+            let from_iana = utcize::<&str>(local, "Europe/Berlin", false, None).unwrap_err();
+            match (from_posix, from_iana) {
+                (
+                    TimeParseError::NonexistentTime {
+                        datetime,
+                        tz,
+                        gap_start,
+                        gap_end,
+                    },
+                    TimeParseError::NonexistentTime {
+                        datetime: iana_datetime,
+                        gap_start: iana_start,
+                        gap_end: iana_end,
+                        ..
+                    },
+                ) => {
+                    assert_eq!(tz, posix);
+                    assert_eq!(
+                        (datetime, gap_start, gap_end),
+                        (iana_datetime, iana_start, iana_end)
+                    );
+                }
+                (from_posix, from_iana) => assert_eq!(from_posix, from_iana),
+            }
+        }
+    }
+
+    #[test]
+    fn test_tzif_footer_extends_transitions() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/zoneinfo");
+        let berlin = TzifProvider::new(dir)
+            .unwrap()
+            .zone("Europe/Berlin")
+            .unwrap();
+        let bundled = BundledProvider.zone("Europe/Berlin").unwrap();
+        for utc in [
+            at(2050, 1, 1, 0, 0),
+            at(2050, 7, 1, 0, 0),
+            at(2099, 3, 29, 0, 59),
+            at(2099, 3, 29, 1, 0),
+        ] {
+            assert_eq!(berlin.offset_at(&utc), bundled.offset_at(&utc), "{utc}");
+        }
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use std::sync::Arc;
+    use utcize::error::TimeParseError;
+    use utcize::provider::{
+        BundledProvider, LocalOffsets, TimeZoneProvider, TransitionZone, TzdataSourceProvider,
+        TzifProvider,
+    };
+    use utcize::types::{AmbiguityPolicy, TimeZoneParsed};
+    use utcize::tz::{TimezoneOptions, parse_timezone_str_with};
+    use utcize::utcizer::Utcizer;
+
+    const DATA: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data");
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    fn tzif() -> TzifProvider {
+        TzifProvider::new(format!("{DATA}/zoneinfo")).unwrap()
+    }
+
+    /// Checks that `provider` agrees with the bundled data for Europe/Berlin.
+    fn assert_matches_bundled(provider: &dyn TimeZoneProvider) {
+        let bundled = BundledProvider.zone("Europe/Berlin").unwrap();
+        let zone = provider.zone("Europe/Berlin").unwrap();
+        for utc in [
+            at(1890, 1, 1, 0, 0),
+            at(1916, 6, 1, 0, 0),
+            at(1945, 6, 1, 0, 0),
+            at(1947, 6, 1, 0, 0),
+            at(2023, 1, 15, 12, 0),
+            at(2023, 3, 26, 0, 59),
+            at(2023, 3, 26, 1, 0),
+            at(2023, 7, 1, 12, 0),
+            at(2023, 10, 29, 0, 59),
+            at(2023, 10, 29, 1, 0),
+        ] {
+            assert_eq!(zone.offset_at(&utc), bundled.offset_at(&utc), "{utc}");
+        }
+    }
+
+    #[test]
+    fn test_bundled_provider() {
+        let provider = BundledProvider;
+        assert_eq!(
+            provider.version().as_deref(),
+            Some(chrono_tz::IANA_TZDB_VERSION)
+        );
+        assert_eq!(
+            provider.zone("Asia/Jakarta").unwrap().name(),
+            "Asia/Jakarta"
+        );
+        assert!(provider.zone("Mars/Olympus").is_none());
+        assert!(
+            provider
+                .zone_names()
+                .iter()
+                .any(|name| name == "Europe/Berlin")
+        );
+    }
+
+    #[test]
+    fn test_tzif_provider() {
+        let provider = tzif();
+        assert_eq!(provider.version().as_deref(), Some("2025b"));
+        assert_eq!(provider.zone_names(), ["Asia/Jakarta", "Europe/Berlin"]);
+        assert!(provider.zone("Asia/Tokyo").is_none());
+        assert!(provider.zone("../zoneinfo/Asia/Jakarta").is_none());
+
+        let jakarta = provider.zone("Asia/Jakarta").unwrap();
+        assert_eq!(
+            jakarta
+                .offset_at(&at(2023, 6, 1, 0, 0))
+                .utc_offset
+                .local_minus_utc(),
+            7 * 3600
+        );
+        assert_matches_bundled(&provider);
+
+        let data = std::fs::read(format!("{DATA}/zoneinfo/Europe/Berlin")).unwrap();
+        let berlin = TransitionZone::from_tzif("Europe/Berlin", &data).unwrap();
+        assert_eq!(berlin.footer(), Some("CET-1CEST,M3.5.0,M10.5.0/3"));
+
+        assert!(matches!(
+            TransitionZone::from_tzif("Broken", b"not tzif"),
+            Err(TimeParseError::TimezoneData { .. })
+        ));
+        assert!(TzifProvider::new(format!("{DATA}/missing")).is_err());
+    }
+
+    #[test]
+    fn test_tzdata_source_provider() {
+        let provider = TzdataSourceProvider::from_dir(format!("{DATA}/tzdata")).unwrap();
+        assert_eq!(provider.version().as_deref(), Some("2025b"));
+        assert_eq!(
+            provider.zone_names(),
+            ["Arctic/Longyearbyen", "Europe/Berlin"]
+        );
+        assert_matches_bundled(&provider);
+
+        let link = provider.zone("Arctic/Longyearbyen").unwrap();
+        assert_eq!(link.name(), "Arctic/Longyearbyen");
+        assert_eq!(
+            link.offset_at(&at(2023, 7, 1, 0, 0)),
+            provider
+                .zone("Europe/Berlin")
+                .unwrap()
+                .offset_at(&at(2023, 7, 1, 0, 0))
+        );
+
+        // Zones must refer to rules defined earlier in the sources.
+        assert!(matches!(
+            TzdataSourceProvider::from_sources(["Zone Test/Zone 1:00 Missing CE%sT"], None),
+            Err(TimeParseError::TimezoneData { .. })
+        ));
+        assert!(TzdataSourceProvider::from_sources(["Zone"], None).is_err());
+
+        let dangling =
+            TzdataSourceProvider::from_sources(["Link Europe/Nowhere Test/Link"], None).unwrap();
+        assert!(dangling.zone("Test/Link").is_none());
+    }
+
+    #[test]
+    fn test_local_offsets() {
+        let berlin = tzif().zone("Europe/Berlin").unwrap();
+
+        assert!(matches!(
+            berlin.local_offsets(&at(2023, 6, 1, 10, 0)),
+            LocalOffsets::Single(offset) if offset.is_dst
+        ));
+        match berlin.local_offsets(&at(2023, 10, 29, 2, 30)) {
+            LocalOffsets::Ambiguous(earlier, later) => {
+                assert!(earlier.is_dst && !later.is_dst);
+            }
+            other => panic!("Expected Ambiguous, got {other:?}"),
+        }
+        match berlin.local_offsets(&at(2023, 3, 26, 2, 30)) {
+            LocalOffsets::Gap { transition, .. } => {
+                assert_eq!(transition, at(2023, 3, 26, 1, 0));
+            }
+            other => panic!("Expected Gap, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_timezone_with_provider() {
+        let options = TimezoneOptions {
+            provider: Some(Arc::new(tzif())),
+            ..Default::default()
+        };
+
+        let berlin = parse_timezone_str_with("europe/berlin", &options).unwrap();
+        assert!(matches!(&berlin, TimeZoneParsed::Iana(tz) if tz.name() == "Europe/Berlin"));
+        assert!(matches!(
+            parse_timezone_str_with("Asia/Tokyo", &options),
+            Err(TimeParseError::UnknownTimezone { .. })
+        ));
+
+        let utcizer = Utcizer::builder().fallback_tz(berlin.clone()).build();
+        match utcizer.parse("2023-03-26 02:30:00").unwrap_err() {
+            TimeParseError::NonexistentTime {
+                gap_start, gap_end, ..
+            } => {
+                assert_eq!(gap_start, at(2023, 3, 26, 2, 0));
+                assert_eq!(gap_end, at(2023, 3, 26, 3, 0));
+            }
+            other => panic!("Expected NonexistentTime, got {other:?}"),
+        }
+
+        let utcizer = Utcizer::builder()
+            .fallback_tz(berlin)
+            .ambiguity_policy(AmbiguityPolicy::PreferStandard)
+            .build();
+        let dt = utcizer.parse("2023-10-29 02:30:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-10-29T01:30:00+00:00");
+    }
+
+    #[test]
+    fn test_utcizer_inline_zone_provider() {
+        let utcizer = Utcizer::builder()
+            .timezone_provider(Arc::new(tzif()))
+            .build();
+        let dt = utcizer.parse("2023-06-01 10:00:00 Asia/Jakarta").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        assert!(utcizer.parse("2023-06-01 10:00:00 Asia/Tokyo").is_err());
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+    use utcize::error::TimeParseError;
+    use utcize::registry::{FormatProfile, FormatRegistry};
+    use utcize::types::{DateOrder, ParseSource};
+    use utcize::tz::parse_timezone_str;
+
+    fn registry() -> FormatRegistry {
+        let registry = FormatRegistry::new();
+        registry
+            .register(
+                FormatProfile::new("base")
+                    .formats(["%Y|%m|%d %H:%M"])
+                    .date_order(DateOrder::DayFirst)
+                    .fallback_tz(parse_timezone_str("Asia/Jakarta").unwrap()),
+            )
+            .unwrap();
+        registry
+            .register(
+                FormatProfile::new("payments-legacy")
+                    .formats(["%d/%m/%Y %Hh%M"])
+                    .fallback_tz(parse_timezone_str("Europe/Berlin").unwrap())
+                    .extends(["base"]),
+            )
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_parse_by_profile() {
+        let registry = registry();
+        let dt = registry.parse("base", "2023|06|01 10:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        // Day-first date order from the profile.
+        let dt = registry.parse("base", "01-06-2023 10:00:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+
+        let outcome = registry
+            .parse_detailed("payments-legacy", "01/06/2023 10h00")
+            .unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+        assert_eq!(
+            outcome.source,
+            ParseSource::CustomFormat("%d/%m/%Y %Hh%M".to_string())
+        );
+    }
+
+    #[test]
+    fn test_profiles_compose() {
+        let registry = registry();
+        let merged = registry.resolve("payments-legacy").unwrap();
+        assert_eq!(
+            merged.custom_formats(),
+            ["%d/%m/%Y %Hh%M", "%Y|%m|%d %H:%M"]
+        );
+        assert_eq!(merged.preferred_date_order(), Some(DateOrder::DayFirst));
+        assert!(merged.extended_profiles().is_empty());
+
+        // Inherited formats, with the profile's own fallback timezone.
+        let dt = registry
+            .parse("payments-legacy", "2023|06|01 10:00")
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_list_replace_and_remove() {
+        let registry = registry();
+        assert_eq!(registry.names(), ["base", "payments-legacy"]);
+        assert_eq!(registry.profiles()[1].extended_profiles(), ["base"]);
+        assert_eq!(
+            registry.profile("base").unwrap().custom_formats(),
+            ["%Y|%m|%d %H:%M"]
+        );
+
+        // Replacing a profile affects the profiles extending it.
+        registry
+            .register(FormatProfile::new("base").fallback_tz(parse_timezone_str("UTC").unwrap()))
+            .unwrap();
+        assert!(
+            registry
+                .parse("payments-legacy", "2023|06|01 10:00")
+                .is_err()
+        );
+
+        assert!(registry.remove("base").is_some());
+        assert_eq!(
+            registry.parse("payments-legacy", "01/06/2023 10h00"),
+            Err(TimeParseError::UnknownProfile {
+                name: "base".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_profiles() {
+        let registry = registry();
+        assert_eq!(
+            registry.parse("nginx", "2023-06-01"),
+            Err(TimeParseError::UnknownProfile {
+                name: "nginx".to_string()
+            })
+        );
+        assert!(matches!(
+            registry.register(FormatProfile::new("bad").formats(["%Q"])),
+            Err(TimeParseError::InvalidFormat { .. })
+        ));
+        assert!(registry.profile("bad").is_none());
+
+        registry
+            .register(FormatProfile::new("a").extends(["b"]))
+            .unwrap();
+        registry
+            .register(FormatProfile::new("b").extends(["a"]))
+            .unwrap();
+        match registry.resolve("a").unwrap_err() {
+            TimeParseError::ProfileCycle { chain } => assert_eq!(chain, ["a", "b", "a"]),
+            other => panic!("Expected ProfileCycle, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_shared_between_threads() {
+        let registry = Arc::new(registry());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let registry = Arc::clone(&registry);
+                thread::spawn(move || registry.parse("base", "2023|06|01 10:00").unwrap())
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(
+                handle.join().unwrap().to_rfc3339(),
+                "2023-06-01T03:00:00+00:00"
+            );
+        }
+    }
+}
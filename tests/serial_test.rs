@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use chrono::format::ParseErrorKind;
+    use utcize::datetime::serial_date_to_naive;
+    use utcize::error::TimeParseError;
+    use utcize::types::{ParseSource, SerialDateSystem};
+    use utcize::tz::parse_timezone_str;
+    use utcize::utcizer::Utcizer;
+
+    fn naive(s: &str, system: SerialDateSystem) -> String {
+        serial_date_to_naive(s, system)
+            .unwrap()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_1900_system_with_lotus_bug() {
+        let system = SerialDateSystem::Excel1900;
+        assert_eq!(naive("1", system), "1900-01-01 00:00:00");
+        assert_eq!(naive("59", system), "1900-02-28 00:00:00");
+        assert_eq!(naive("61", system), "1900-03-01 00:00:00");
+        assert_eq!(naive("45078", system), "2023-06-01 00:00:00");
+        assert_eq!(naive("2958465", system), "9999-12-31 00:00:00");
+
+        match serial_date_to_naive("60", system).unwrap().unwrap_err() {
+            TimeParseError::InvalidFieldValue { kind, .. } => {
+                assert_eq!(kind, ParseErrorKind::Impossible)
+            }
+            e => panic!("Expected InvalidFieldValue, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_1904_system_and_fractions() {
+        let system = SerialDateSystem::Excel1904;
+        assert_eq!(naive("0", system), "1904-01-01 00:00:00");
+        assert_eq!(naive("43616.75", system), "2023-06-01 18:00:00");
+        assert_eq!(
+            naive("45078.41666666667", SerialDateSystem::Excel1900),
+            "2023-06-01 10:00:00"
+        );
+    }
+
+    #[test]
+    fn test_not_a_serial() {
+        let system = SerialDateSystem::Excel1900;
+        assert!(serial_date_to_naive("-1", system).is_none());
+        assert!(serial_date_to_naive("1.2.3", system).is_none());
+        assert!(serial_date_to_naive(".5", system).is_none());
+        assert!(serial_date_to_naive("2958466", system).is_none());
+    }
+
+    #[test]
+    fn test_utcizer_resolves_serials_in_fallback() {
+        let utcizer = Utcizer::builder()
+            .fallback_tz(parse_timezone_str("Asia/Jakarta").unwrap())
+            .serial_dates(SerialDateSystem::Excel1900)
+            .build();
+
+        let outcome = utcizer.parse_detailed("45078.41666666667").unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        assert_eq!(
+            outcome.source,
+            ParseSource::SerialDate(SerialDateSystem::Excel1900)
+        );
+        assert!(outcome.used_fallback);
+        assert_eq!(outcome.epoch_kind, None);
+
+        // Values beyond the spreadsheet range are still epochs.
+        let outcome = utcizer.parse_detailed("1685588400").unwrap();
+        assert_eq!(outcome.source, ParseSource::Epoch);
+    }
+
+    #[test]
+    fn test_serials_are_opt_in() {
+        // Too short to be detected as an epoch either.
+        assert!(Utcizer::builder().build().parse("45078").is_err());
+        assert!(Utcizer::builder().build().parse("45078.5").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use utcize::error::TimeParseError;
+    use utcize::types::TimeZoneParsed;
+    use utcize::tz::{parse_timezone_str, suggest_timezones};
+
+    #[test]
+    fn test_case_insensitive_names() {
+        for name in ["asia/jakarta", "ASIA/JAKARTA", "Asia/jakarta"] {
+            match parse_timezone_str(name).unwrap() {
+                TimeZoneParsed::Iana(tz) => assert_eq!(tz.name(), "Asia/Jakarta"),
+                other => panic!("Expected Iana, got {other:?}"),
+            }
+        }
+        match parse_timezone_str("america/argentina/buenos_aires").unwrap() {
+            TimeZoneParsed::Iana(tz) => assert_eq!(tz.name(), "America/Argentina/Buenos_Aires"),
+            other => panic!("Expected Iana, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_misspelled_name_suggestions() {
+        match parse_timezone_str("Asia/Jakatra").unwrap_err() {
+            TimeParseError::UnknownTimezone { name, suggestions } => {
+                assert_eq!(name, "Asia/Jakatra");
+                assert_eq!(
+                    suggestions.first().map(String::as_str),
+                    Some("Asia/Jakarta")
+                );
+            }
+            e => panic!("Expected UnknownTimezone, got {e:?}"),
+        }
+
+        let err = parse_timezone_str("Europe/Berln").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown IANA timezone 'Europe/Berln', did you mean 'Europe/Berlin'?"
+        );
+    }
+
+    #[test]
+    fn test_city_only_suggestions() {
+        assert_eq!(suggest_timezones("Jakatra")[0], "Asia/Jakarta");
+        assert!(suggest_timezones("tokyo").contains(&"Asia/Tokyo".to_string()));
+    }
+
+    #[test]
+    fn test_suggestions_are_limited_and_ordered() {
+        let suggestions = suggest_timezones("America/Indiana/Knox_");
+        assert!(suggestions.len() <= 5);
+        assert_eq!(suggestions[0], "America/Indiana/Knox");
+        assert!(suggest_timezones("Nowhere/Atall").is_empty());
+    }
+}
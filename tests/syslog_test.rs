@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use utcize::error::TimeParseError;
+    use utcize::syslog::parse_syslog_timestamp;
+    use utcize::types::{ParseSource, ParsedDatetime, SyslogFormat, SyslogYear};
+    use utcize::tz::parse_timezone_str;
+    use utcize::utcizer::Utcizer;
+
+    fn naive(s: &str, year: SyslogYear) -> String {
+        match parse_syslog_timestamp(s, year).unwrap().unwrap() {
+            ParsedDatetime::Naive(dt) => dt.to_string(),
+            other => panic!("Expected Naive, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rfc3164_padding() {
+        let year = SyslogYear::Fixed(2023);
+        assert_eq!(naive("Jun  1 10:00:00", year), "2023-06-01 10:00:00");
+        assert_eq!(naive("Jun 01 10:00:00", year), "2023-06-01 10:00:00");
+        assert_eq!(naive("Jun 1 10:00:00", year), "2023-06-01 10:00:00");
+        assert_eq!(naive("Oct 11 22:14:15", year), "2023-10-11 22:14:15");
+        assert_eq!(
+            naive("Jun  1 10:00:00.250", year),
+            "2023-06-01 10:00:00.250"
+        );
+
+        for impossible in ["Feb 30 10:00:00", "Jun 31 10:00:00", "Jun  1 25:00:00"] {
+            assert!(
+                matches!(
+                    parse_syslog_timestamp(impossible, year),
+                    Some(Err(TimeParseError::InvalidFieldValue { .. }))
+                ),
+                "{impossible}"
+            );
+        }
+        assert!(parse_syslog_timestamp("2023-06-01 10:00:00", year).is_none());
+        assert!(parse_syslog_timestamp("June 1st", year).is_none());
+    }
+
+    #[test]
+    fn test_rfc3164_year_inference() {
+        let new_year = Utc.with_ymd_and_hms(2024, 1, 1, 0, 5, 0).unwrap();
+        let year = SyslogYear::Relative(new_year);
+        assert_eq!(naive("Dec 31 23:59:59", year), "2023-12-31 23:59:59");
+        assert_eq!(naive("Jan  1 00:04:00", year), "2024-01-01 00:04:00");
+        // A sender with a clock slightly ahead still lands in the same year.
+        assert_eq!(naive("Jan  1 00:10:00", year), "2024-01-01 00:10:00");
+
+        let new_year_eve = Utc.with_ymd_and_hms(2023, 12, 31, 23, 59, 0).unwrap();
+        let year = SyslogYear::Relative(new_year_eve);
+        assert_eq!(naive("Jan  1 00:00:30", year), "2024-01-01 00:00:30");
+        assert_eq!(naive("Jun  1 10:00:00", year), "2023-06-01 10:00:00");
+
+        // Feb 29 goes back to the last leap year.
+        let reference = Utc.with_ymd_and_hms(2027, 3, 1, 0, 0, 0).unwrap();
+        let year = SyslogYear::Relative(reference);
+        assert_eq!(naive("Feb 29 12:00:00", year), "2024-02-29 12:00:00");
+        let reference = Utc.with_ymd_and_hms(2028, 2, 28, 0, 0, 0).unwrap();
+        let year = SyslogYear::Relative(reference);
+        assert_eq!(naive("Feb 29 12:00:00", year), "2028-02-29 12:00:00");
+    }
+
+    #[test]
+    fn test_rfc5424_and_nilvalue() {
+        let year = SyslogYear::Current;
+        match parse_syslog_timestamp("2023-06-01T10:00:00.123456+07:00", year)
+            .unwrap()
+            .unwrap()
+        {
+            ParsedDatetime::WithTimezone(dt) => {
+                assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00.123456+00:00")
+            }
+            other => panic!("Expected WithTimezone, got {other:?}"),
+        }
+        assert!(matches!(
+            parse_syslog_timestamp("2003-10-11T22:14:15.003Z", year),
+            Some(Ok(ParsedDatetime::WithTimezone(_)))
+        ));
+        assert!(matches!(
+            parse_syslog_timestamp("2023-02-30T10:00:00Z", year),
+            Some(Err(TimeParseError::InvalidFieldValue { .. }))
+        ));
+        assert_eq!(
+            parse_syslog_timestamp("-", year).unwrap().unwrap_err(),
+            TimeParseError::NilTimestamp
+        );
+    }
+
+    #[test]
+    fn test_utcizer_syslog_mode() {
+        let utcizer = Utcizer::builder()
+            .fallback_tz(parse_timezone_str("Asia/Jakarta").unwrap())
+            .syslog(SyslogYear::Fixed(2023))
+            .build();
+
+        let outcome = utcizer.parse_detailed("Jun  1 10:00:00").unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        assert_eq!(outcome.source, ParseSource::Syslog(SyslogFormat::Rfc3164));
+        assert!(outcome.used_fallback);
+
+        let outcome = utcizer
+            .parse_detailed("2023-06-01T10:00:00.123456+07:00")
+            .unwrap();
+        assert_eq!(outcome.utc.to_rfc3339(), "2023-06-01T03:00:00.123456+00:00");
+        assert_eq!(outcome.source, ParseSource::Syslog(SyslogFormat::Rfc5424));
+        assert!(!outcome.used_fallback);
+
+        assert_eq!(
+            utcizer.parse("-").unwrap_err(),
+            TimeParseError::NilTimestamp
+        );
+        assert_eq!(
+            TimeParseError::NilTimestamp.to_string(),
+            "syslog timestamp is NILVALUE '-', the sender did not know the time"
+        );
+
+        // Other inputs are parsed as usual.
+        let dt = utcizer.parse("2023-06-01 10:00:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+
+        // Without syslog mode, RFC 3164 timestamps have no year to match.
+        let plain = Utcizer::builder().build();
+        assert!(plain.parse("Jun  1 10:00:00").is_err());
+        assert!(plain.parse("-").is_err());
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use utcize::datetime::utcize;
+    use utcize::error::TimeParseError;
+    use utcize::types::TimeZoneParsed;
+    use utcize::tz::{
+        TimezoneOptions, TimezoneWarning, deprecated_link_target, parse_timezone_str,
+        parse_timezone_str_detailed, parse_timezone_str_with,
+    };
+
+    fn iana_name(s: &str) -> String {
+        match parse_timezone_str(s).unwrap() {
+            TimeZoneParsed::Iana(tz) => tz.name().to_string(),
+            other => panic!("Expected Iana for {s}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_single_segment_names() {
+        for name in [
+            "GMT",
+            "EST5EDT",
+            "CET",
+            "Japan",
+            "Singapore",
+            "Zulu",
+            "NZ",
+            "EST",
+        ] {
+            assert_eq!(iana_name(name), name);
+        }
+        assert_eq!(
+            iana_name("America/Argentina/Buenos_Aires"),
+            "America/Argentina/Buenos_Aires"
+        );
+    }
+
+    #[test]
+    fn test_single_segment_fallback() {
+        let dt = utcize::<&str>("2023-06-01 10:00:00", "Japan", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T01:00:00+00:00");
+
+        // CET is the tz database zone, with summer time.
+        let dt = utcize::<&str>("2023-06-01 10:00:00", "CET", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_unknown_and_malformed_names() {
+        assert!(matches!(
+            parse_timezone_str("Atlantis"),
+            Err(TimeParseError::UnknownTimezone { .. })
+        ));
+        for name in ["Asia//Jakarta", "Asia/Ja karta", "0800", ""] {
+            assert!(
+                matches!(
+                    parse_timezone_str(name),
+                    Err(TimeParseError::MalformedTimezone { .. })
+                ),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_deprecated_links() {
+        assert_eq!(
+            deprecated_link_target("Asia/Calcutta"),
+            Some("Asia/Kolkata")
+        );
+        assert_eq!(deprecated_link_target("Asia/Kolkata"), None);
+
+        let parsed =
+            parse_timezone_str_detailed("US/Eastern", &TimezoneOptions::default()).unwrap();
+        assert_eq!(
+            parsed.warnings,
+            vec![TimezoneWarning::DeprecatedLink {
+                name: "US/Eastern".into(),
+                replacement: "America/New_York".into(),
+            }]
+        );
+
+        let strict = TimezoneOptions {
+            reject_deprecated: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_timezone_str_with("Japan", &strict).unwrap_err(),
+            TimeParseError::DeprecatedTimezone {
+                name: "Japan".into(),
+                replacement: "Asia/Tokyo".into(),
+            }
+        );
+        assert!(parse_timezone_str_with("Asia/Tokyo", &strict).is_ok());
+        assert!(parse_timezone_str_with("UTC", &strict).is_ok());
+    }
+
+    #[test]
+    fn test_merged_zones_are_not_deprecated() {
+        let strict = TimezoneOptions {
+            reject_deprecated: true,
+            ..Default::default()
+        };
+        for name in [
+            "Europe/Oslo",
+            "Europe/Amsterdam",
+            "Europe/Stockholm",
+            "Africa/Accra",
+            "Asia/Kuala_Lumpur",
+            "Atlantic/Reykjavik",
+        ] {
+            assert_eq!(deprecated_link_target(name), None, "{name}");
+            assert!(parse_timezone_str_with(name, &strict).is_ok(), "{name}");
+            let parsed = parse_timezone_str_detailed(name, &TimezoneOptions::default()).unwrap();
+            assert!(parsed.warnings.is_empty(), "{name}");
+        }
+        // Aliases of merged zones point to the country's zone.
+        assert_eq!(
+            deprecated_link_target("Iceland"),
+            Some("Atlantic/Reykjavik")
+        );
+    }
+
+    #[test]
+    fn test_inverted_etc_sign_warning() {
+        let options = TimezoneOptions::default();
+        let parsed = parse_timezone_str_detailed("Etc/GMT+7", &options).unwrap();
+        match &parsed.warnings[..] {
+            [TimezoneWarning::InvertedEtcSign { name, offset }] => {
+                assert_eq!(name, "Etc/GMT+7");
+                assert_eq!(offset.local_minus_utc(), -7 * 3600);
+            }
+            other => panic!("Expected InvertedEtcSign, got {other:?}"),
+        }
+
+        let parsed = parse_timezone_str_detailed("Etc/GMT-14", &options).unwrap();
+        assert!(matches!(
+            &parsed.warnings[..],
+            [TimezoneWarning::InvertedEtcSign { offset, .. }] if offset.local_minus_utc() == 14 * 3600
+        ));
+
+        for name in ["Etc/GMT", "Etc/GMT+0", "Asia/Jakarta"] {
+            let parsed = parse_timezone_str_detailed(name, &options).unwrap();
+            assert!(
+                !parsed
+                    .warnings
+                    .iter()
+                    .any(|w| matches!(w, TimezoneWarning::InvertedEtcSign { .. })),
+                "{name}"
+            );
+        }
+    }
+}
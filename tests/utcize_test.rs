@@ -60,7 +60,7 @@ mod tests {
         assert!(format!("{}", err2).contains("Invalid IANA timezone format"));
 
         let err3 = parse_timezone_str("Asia").unwrap_err();
-        assert!(format!("{}", err3).contains("Invalid IANA timezone format"));
+        assert!(format!("{}", err3).contains("Unknown IANA timezone"));
     }
 
     #[test]
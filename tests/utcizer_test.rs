@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+    use utcize::datetime::utcize;
+    use utcize::types::{DateOrder, TimeZoneParsed};
+    use utcize::tz::parse_timezone_str;
+    use utcize::utcizer::Utcizer;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_utcizer_is_send_sync() {
+        assert_send_sync::<Utcizer>();
+    }
+
+    #[test]
+    fn test_default_fallback_is_utc() {
+        let utcizer = Utcizer::builder().build();
+        match utcizer.fallback_tz() {
+            TimeZoneParsed::FixedOffset(offset) => assert_eq!(offset.local_minus_utc(), 0),
+            _ => panic!("Expected FixedOffset"),
+        }
+
+        let dt = utcizer.parse("2023-06-01 10:00:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T10:00:00+00:00");
+    }
+
+    #[test]
+    fn test_builder_settings() {
+        let utcizer = Utcizer::builder()
+            .fallback_tz(parse_timezone_str("Europe/Paris").unwrap())
+            .date_order(DateOrder::DayFirst)
+            .custom_formats(["%Y|%m|%d %H:%M"])
+            .build();
+
+        let dt = utcizer.parse("01-06-2023 10:00:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+
+        let dt = utcizer.parse("2023|06|01 10:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_matches_free_function() {
+        let utcizer = Utcizer::builder()
+            .fallback_tz(parse_timezone_str("+07:00").unwrap())
+            .build();
+
+        for input in [
+            "2023-06-01 10:00:00",
+            "06/01/2023",
+            "1685588400",
+            "2023-06-01T10:00:00Z",
+        ] {
+            let expected = utcize::<&str>(input, "+07:00", false, None).unwrap();
+            assert_eq!(utcizer.parse(input).unwrap(), expected, "input: {input}");
+        }
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        let utcizer = Arc::new(
+            Utcizer::builder()
+                .fallback_tz(parse_timezone_str("Asia/Jakarta").unwrap())
+                .build(),
+        );
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let utcizer = Arc::clone(&utcizer);
+                thread::spawn(move || utcizer.parse("2023-06-01 10:00:00").unwrap())
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(
+                handle.join().unwrap().to_rfc3339(),
+                "2023-06-01T03:00:00+00:00"
+            );
+        }
+    }
+
+    #[test]
+    fn test_one_off_calls_share_default_settings() {
+        // Calls without custom formats run on a shared parser, but each applies its own
+        // fallback timezone and date order.
+        let jakarta = utcize::<&str>("01-06-2023 10:00:00", "Asia/Jakarta", true, None).unwrap();
+        assert_eq!(jakarta.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        let berlin = utcize::<&str>("01-06-2023 10:00:00", "Europe/Berlin", true, None).unwrap();
+        assert_eq!(berlin.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+        let us = utcize::<&str>("01-06-2023 10:00:00", "Europe/Berlin", false, None).unwrap();
+        assert_eq!(us.to_rfc3339(), "2023-01-06T09:00:00+00:00");
+
+        let custom = ["%Y|%m|%d %H:%M"];
+        let dt = utcize("2023|06|01 10:00", "Asia/Jakarta", false, Some(&custom)).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        assert!(utcize::<&str>("2023|06|01 10:00", "Asia/Jakarta", false, None).is_err());
+    }
+
+    #[test]
+    fn test_fallback_tz_only_parsed_for_naive_input() {
+        let dt = utcize::<&str>("2023-06-01T10:00:00Z", "", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T10:00:00+00:00");
+        let dt = utcize::<&str>("1685588400", "garbage", false, None).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+        let custom = ["%Y|%m|%d %H:%M %z"];
+        let dt = utcize("2023|06|01 10:00 +0700", "garbage", false, Some(&custom)).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T03:00:00+00:00");
+
+        // A naive input still needs a valid fallback timezone.
+        assert!(utcize::<&str>("2023-06-01 10:00:00", "garbage", false, None).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use utcize::datetime::utcize;
+    use utcize::registry::{FormatProfile, FormatRegistry};
+    use utcize::tz::parse_timezone_str;
+
+    #[test]
+    fn test_access_log_timestamps() {
+        let registry = FormatRegistry::with_builtin_profiles();
+        assert_eq!(registry.names(), ["web-server-log"]);
+
+        for input in ["[10/Oct/2000:13:55:36 -0700]", "10/Oct/2000:13:55:36 -0700"] {
+            let dt = registry.parse("web-server-log", input).unwrap();
+            assert_eq!(dt.to_rfc3339(), "2000-10-10T20:55:36+00:00", "{input}");
+        }
+
+        let dt = registry
+            .parse("web-server-log", "[01/Jun/2023:10:00:00.250 +0200]")
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00.250+00:00");
+    }
+
+    #[test]
+    fn test_error_log_timestamps() {
+        let registry = FormatRegistry::with_builtin_profiles();
+        let dt = registry
+            .parse("web-server-log", "[Thu Jun 01 10:00:00.123456 2023]")
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T10:00:00.123456+00:00");
+        let dt = registry
+            .parse("web-server-log", "Thu Jun 01 10:00:00 2023")
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T10:00:00+00:00");
+
+        // Timestamps without an offset are read in the fallback timezone of an extending profile.
+        registry
+            .register(
+                FormatProfile::new("nginx")
+                    .fallback_tz(parse_timezone_str("Europe/Berlin").unwrap())
+                    .extends(["web-server-log"]),
+            )
+            .unwrap();
+        let dt = registry.parse("nginx", "2023/06/01 10:00:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+        let dt = registry
+            .parse("nginx", "[Thu Jun 01 10:00:00.123456 2023]")
+            .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00.123456+00:00");
+    }
+
+    #[test]
+    fn test_not_in_default_formats() {
+        let formats = utcize::formats::web_server_log_formats();
+        assert!(utcize::<&str>("[10/Oct/2000:13:55:36 -0700]", "UTC", false, None).is_err());
+        let dt = utcize("[10/Oct/2000:13:55:36 -0700]", "UTC", false, Some(&formats)).unwrap();
+        assert_eq!(dt.to_rfc3339(), "2000-10-10T20:55:36+00:00");
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use utcize::datetime::utcize;
+    use utcize::types::TimeZoneParsed;
+    use utcize::tz::{TimezoneOptions, parse_timezone_str, parse_timezone_str_with};
+    use utcize::windows::{iana_to_windows, windows_to_iana};
+
+    fn iana_name(parsed: TimeZoneParsed) -> String {
+        match parsed {
+            TimeZoneParsed::Iana(tz) => tz.name().to_string(),
+            other => panic!("Expected Iana, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_windows_names_resolve_to_iana() {
+        assert_eq!(
+            iana_name(parse_timezone_str("SE Asia Standard Time").unwrap()),
+            "Asia/Bangkok"
+        );
+        assert_eq!(
+            iana_name(parse_timezone_str("W. Europe Standard Time").unwrap()),
+            "Europe/Berlin"
+        );
+        assert_eq!(
+            iana_name(parse_timezone_str("pacific standard time").unwrap()),
+            "America/Los_Angeles"
+        );
+        match parse_timezone_str("Tokyo Standard Time").unwrap() {
+            TimeZoneParsed::Iana(tz) => assert_eq!(tz.as_tz(), Some(chrono_tz::Asia::Tokyo)),
+            other => panic!("Expected Iana, got {other:?}"),
+        }
+        // `parse_timezone_str` reads `UTC-11` as the equivalent fixed offset.
+        assert_eq!(windows_to_iana("UTC-11", None).unwrap().name(), "Etc/GMT+11");
+    }
+
+    #[test]
+    fn test_territory_selection() {
+        let options = TimezoneOptions {
+            windows_territory: Some("ID".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            iana_name(parse_timezone_str_with("SE Asia Standard Time", &options).unwrap()),
+            "Asia/Jakarta"
+        );
+
+        // Unknown territories fall back to the default zone.
+        let tz = windows_to_iana("Tokyo Standard Time", Some("US")).unwrap();
+        assert_eq!(tz.name(), "Asia/Tokyo");
+        assert_eq!(
+            windows_to_iana("Tokyo Standard Time", Some("ID"))
+                .unwrap()
+                .name(),
+            "Asia/Jayapura"
+        );
+    }
+
+    #[test]
+    fn test_windows_fallback_tz() {
+        let dt = utcize::<&str>(
+            "2023-06-01 10:00:00",
+            "W. Europe Standard Time",
+            false,
+            None,
+        )
+        .unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-06-01T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_reverse_lookup() {
+        assert_eq!(
+            iana_to_windows("Asia/Jakarta"),
+            Some("SE Asia Standard Time")
+        );
+        assert_eq!(
+            iana_to_windows("America/New_York"),
+            Some("Eastern Standard Time")
+        );
+        assert_eq!(iana_to_windows("Asia/Kolkata"), Some("India Standard Time"));
+        assert_eq!(iana_to_windows("Etc/UTC"), Some("UTC"));
+        assert_eq!(iana_to_windows("Mars/Olympus"), None);
+    }
+
+    #[test]
+    fn test_reverse_lookup_round_trips() {
+        for name in [
+            "Europe/Paris",
+            "Australia/Melbourne",
+            "America/Sao_Paulo",
+            "Africa/Nairobi",
+        ] {
+            let windows = iana_to_windows(name).unwrap();
+            assert!(windows_to_iana(windows, None).is_some(), "{windows}");
+        }
+    }
+}